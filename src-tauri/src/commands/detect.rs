@@ -0,0 +1,211 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::commands::error::CommandResult;
+use crate::commands::fs::home_dir;
+use crate::commands::platforms::{all_platforms, PlatformSpec};
+use crate::commands::retry::run_blocking;
+
+/// 获取版本号的超时时间（部分 GUI 应用的 CLI 启动较慢）
+const VERSION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 判定平台已安装的依据
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DetectionEvidence {
+    /// 在 PATH 中找到可执行文件
+    Path,
+    /// 在常见安装位置找到可执行文件或应用（GUI 启动时不继承 shell 的 PATH）
+    KnownLocation,
+    ConfigDir,
+    SkillsDir,
+}
+
+/// 单个平台的检测结果
+#[derive(Debug, Clone, Serialize)]
+pub struct PlatformDetection {
+    pub id: String,
+    pub label: String,
+    /// 判定为已安装：在 PATH 或常见安装位置找到可执行文件；平台未定义可执行文件时以配置目录为准。
+    /// 配置目录 / skills 目录可能是卸载后的残留或与其他 agent 共用，只作为参考信息
+    pub installed: bool,
+    /// 检测到的依据，可能有多项
+    pub evidence: Vec<DetectionEvidence>,
+    pub binary_found: bool,
+    pub binary_path: Option<String>,
+    pub version: Option<String>,
+    pub config_dir_found: bool,
+    pub config_dir: Option<String>,
+    pub skills_dir_present: bool,
+    pub skills_dir: Option<String>,
+}
+
+/// 在 PATH 中查找可执行文件（Windows 下按 PATHEXT 补全扩展名）
+pub(crate) fn find_on_path(name: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    find_in_dirs(name, std::env::split_paths(&path))
+}
+
+/// 不在 PATH 中时查找的常见安装目录（从 Dock / 开始菜单启动的应用拿不到 shell 配置的 PATH）
+fn known_bin_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![];
+    if let Ok(home) = home_dir() {
+        for rel in [".local/bin", ".claude/local", ".npm-global/bin", ".bun/bin", ".volta/bin", ".cargo/bin", "bin"] {
+            dirs.push(home.join(rel));
+        }
+    }
+    if cfg!(target_os = "windows") {
+        if let Some(appdata) = std::env::var_os("APPDATA") {
+            dirs.push(PathBuf::from(appdata).join("npm"));
+        }
+    } else {
+        dirs.extend(["/opt/homebrew/bin", "/usr/local/bin", "/usr/bin", "/snap/bin"].map(PathBuf::from));
+    }
+    dirs
+}
+
+/// macOS 下平台对应的 .app（按显示名称查找 /Applications 与 ~/Applications）
+fn find_mac_app(label: &str) -> Option<PathBuf> {
+    if !cfg!(target_os = "macos") {
+        return None;
+    }
+    let app = format!("{label}.app");
+    let user_apps = home_dir().ok().map(|h| h.join("Applications"));
+    [Some(PathBuf::from("/Applications")), user_apps].into_iter().flatten().map(|d| d.join(&app)).find(|p| p.is_dir())
+}
+
+fn find_in_dirs(name: &str, dirs: impl IntoIterator<Item = PathBuf>) -> Option<PathBuf> {
+    let exts: Vec<String> = if cfg!(target_os = "windows") {
+        std::env::var("PATHEXT")
+            .unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".into())
+            .split(';')
+            .filter(|e| !e.is_empty())
+            .map(|e| e.to_lowercase())
+            .collect()
+    } else {
+        vec![String::new()]
+    };
+    dirs.into_iter().find_map(|dir| {
+        exts.iter()
+            .map(|ext| dir.join(format!("{name}{ext}")))
+            .find(|p| is_executable(p))
+    })
+}
+
+#[cfg(unix)]
+fn is_executable(p: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(p).map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0).unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(p: &Path) -> bool {
+    p.is_file()
+}
+
+/// 执行 `<binary> --version`，超时则终止进程；返回首个非空行
+fn binary_version(binary: &Path) -> Option<String> {
+    let is_script = binary
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("cmd") || e.eq_ignore_ascii_case("bat"));
+    let mut cmd = if is_script {
+        let mut c = Command::new("cmd");
+        c.arg("/c").arg(binary);
+        c
+    } else {
+        Command::new(binary)
+    };
+    let mut child = cmd
+        .arg("--version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() < VERSION_TIMEOUT => std::thread::sleep(Duration::from_millis(50)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+        }
+    };
+    if !status.success() {
+        return None;
+    }
+    let mut out = String::new();
+    child.stdout.take()?.read_to_string(&mut out).ok()?;
+    out.lines().map(str::trim).find(|l| !l.is_empty()).map(str::to_string)
+}
+
+/// 检测单个平台；with_version 为 false 时不执行可执行文件（用于安装前的快速判断）
+pub(crate) fn detect_platform(spec: &PlatformSpec, with_version: bool) -> PlatformDetection {
+    let mut evidence = vec![];
+    let on_path = spec.binaries.iter().find_map(|b| find_on_path(b));
+    let binary = match on_path {
+        Some(p) => {
+            evidence.push(DetectionEvidence::Path);
+            Some(p)
+        }
+        None => {
+            let known = spec
+                .binaries
+                .iter()
+                .find_map(|b| find_in_dirs(b, known_bin_dirs()))
+                .or_else(|| find_mac_app(&spec.label));
+            if known.is_some() {
+                evidence.push(DetectionEvidence::KnownLocation);
+            }
+            known
+        }
+    };
+    let config_dir = spec.detection_path().ok();
+    let skills_dir = spec.global_skills_dir().ok();
+    let config_dir_found = config_dir.as_ref().is_some_and(|p| p.exists());
+    let skills_dir_present = skills_dir.as_ref().is_some_and(|p| p.is_dir());
+    if config_dir_found {
+        evidence.push(DetectionEvidence::ConfigDir);
+    }
+    if skills_dir_present {
+        evidence.push(DetectionEvidence::SkillsDir);
+    }
+
+    PlatformDetection {
+        id: spec.id.clone(),
+        label: spec.label.clone(),
+        installed: binary.is_some() || (spec.binaries.is_empty() && config_dir_found),
+        evidence,
+        binary_found: binary.is_some(),
+        // .app 目录无法直接执行 --version
+        version: binary.as_deref().filter(|p| with_version && p.is_file()).and_then(binary_version),
+        binary_path: binary.map(|p| p.display().to_string()),
+        config_dir_found,
+        config_dir: config_dir.map(|p| p.display().to_string()),
+        skills_dir_present,
+        skills_dir: skills_dir.map(|p| p.display().to_string()),
+    }
+}
+
+/// 检测所有平台：可执行文件、版本、配置目录与 skills 目录（在阻塞线程池中并行获取版本）
+#[tauri::command]
+pub async fn detect_platforms() -> CommandResult<Vec<PlatformDetection>> {
+    run_blocking(|| {
+        let specs = all_platforms();
+        let detections = std::thread::scope(|scope| {
+            let handles: Vec<_> = specs.iter().map(|spec| scope.spawn(|| detect_platform(spec, true))).collect();
+            handles.into_iter().filter_map(|h| h.join().ok()).collect()
+        });
+        Ok(detections)
+    })
+    .await
+}
@@ -0,0 +1,283 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use serde::Serialize;
+
+use crate::commands::error::CommandResult;
+use crate::commands::fs::{find_skill_md, home_dir};
+use crate::commands::git::{npx_command, CLONE_TEMP_PREFIX};
+use crate::commands::platforms::all_platforms;
+use crate::commands::retry::run_blocking;
+use crate::commands::watcher::watcher_error;
+
+/// 检查结果级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FindingLevel {
+    Ok,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorFinding {
+    /// 检查项标识，如 git / npx / skills_dir_writable
+    pub check: &'static str,
+    pub level: FindingLevel,
+    pub message: String,
+    pub path: Option<String>,
+    /// 建议的修复方式
+    pub fix: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DoctorReport {
+    pub git_version: Option<String>,
+    /// git >= 2.25 才支持 sparse-checkout（cone 模式）
+    pub sparse_checkout: bool,
+    pub node_version: Option<String>,
+    pub npx_available: bool,
+    /// 本地缓存的 skills CLI 版本（未缓存时首次安装会自动下载）
+    pub skills_cli_version: Option<String>,
+    pub findings: Vec<DoctorFinding>,
+}
+
+impl DoctorReport {
+    fn push(&mut self, check: &'static str, level: FindingLevel, message: String, path: Option<&Path>, fix: Option<&str>) {
+        self.findings.push(DoctorFinding {
+            check,
+            level,
+            message,
+            path: path.map(|p| p.display().to_string()),
+            fix: fix.map(str::to_string),
+        });
+    }
+}
+
+/// 执行命令并返回首行输出（失败返回 None）
+fn first_line(mut cmd: Command) -> Option<String> {
+    let out = cmd.output().ok()?;
+    if !out.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&out.stdout)
+        .lines()
+        .next()
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+}
+
+/// 从 "git version 2.39.2 (Apple Git-143)" 中解析出 (major, minor)
+fn parse_git_version(v: &str) -> Option<(u32, u32)> {
+    let ver = v.split_whitespace().find(|w| w.chars().next().is_some_and(|c| c.is_ascii_digit()))?;
+    let mut parts = ver.split('.').map(|p| p.parse::<u32>().ok());
+    Some((parts.next()??, parts.next()??))
+}
+
+fn check_git(report: &mut DoctorReport) {
+    let mut cmd = Command::new("git");
+    cmd.arg("--version");
+    let Some(version) = first_line(cmd) else {
+        report.push(
+            "git",
+            FindingLevel::Error,
+            "未检测到 git".into(),
+            None,
+            Some("安装 Git（https://git-scm.com/downloads）并确保 git 在 PATH 中，然后重启 SkillHub"),
+        );
+        return;
+    };
+    report.sparse_checkout = parse_git_version(&version).is_some_and(|v| v >= (2, 25));
+    if report.sparse_checkout {
+        report.push("git", FindingLevel::Ok, version.clone(), None, None);
+    } else {
+        report.push(
+            "git",
+            FindingLevel::Warning,
+            format!("{version} 不支持 sparse-checkout，带 subPath 的安装将失败"),
+            None,
+            Some("升级 Git 到 2.25 或更高版本"),
+        );
+    }
+    report.git_version = Some(version);
+}
+
+fn check_node(report: &mut DoctorReport) {
+    let mut node = Command::new("node");
+    node.arg("--version");
+    report.node_version = first_line(node);
+    report.npx_available = first_line(npx_command(&["--version"])).is_some();
+
+    if !report.npx_available {
+        report.push(
+            "npx",
+            FindingLevel::Warning,
+            "未检测到 node/npx，安装将回退到 git".into(),
+            None,
+            Some("安装 Node.js（https://nodejs.org）以使用 skills CLI 安装"),
+        );
+        return;
+    }
+    report.push(
+        "npx",
+        FindingLevel::Ok,
+        format!("node {}", report.node_version.as_deref().unwrap_or("?")),
+        None,
+        None,
+    );
+
+    report.skills_cli_version = skills_cli_version();
+    match &report.skills_cli_version {
+        Some(v) => report.push("skills_cli", FindingLevel::Ok, format!("skills CLI {v}"), None, None),
+        None => report.push(
+            "skills_cli",
+            FindingLevel::Ok,
+            "skills CLI 未缓存，首次安装时将自动下载".into(),
+            None,
+            None,
+        ),
+    }
+}
+
+/// skills CLI 版本：优先全局安装的 skills 命令，其次 npx 缓存（不触发下载，npx --no-install 仍会访问 registry）
+fn skills_cli_version() -> Option<String> {
+    let global = if cfg!(target_os = "windows") {
+        let mut c = Command::new("cmd");
+        c.args(["/c", "skills", "--version"]);
+        c
+    } else {
+        let mut c = Command::new("skills");
+        c.arg("--version");
+        c
+    };
+    if let Some(v) = first_line(global) {
+        return Some(v);
+    }
+
+    let mut cache_roots = vec![];
+    if let Ok(home) = home_dir() {
+        cache_roots.push(home.join(".npm").join("_npx"));
+    }
+    if let Ok(local) = std::env::var("LOCALAPPDATA") {
+        cache_roots.push(Path::new(&local).join("npm-cache").join("_npx"));
+    }
+    cache_roots
+        .iter()
+        .filter_map(|root| fs::read_dir(root).ok())
+        .flat_map(|rd| rd.flatten())
+        .filter_map(|ent| fs::read_to_string(ent.path().join("node_modules/skills/package.json")).ok())
+        .filter_map(|text| serde_json::from_str::<serde_json::Value>(&text).ok())
+        .find_map(|pkg| pkg.get("version").and_then(|v| v.as_str()).map(str::to_string))
+}
+
+/// 目录不存在时检查最近的已存在祖先目录是否可写
+fn is_writable(dir: &Path) -> bool {
+    let Some(existing) = dir.ancestors().find(|p| p.exists()) else {
+        return false;
+    };
+    let probe = existing.join(format!(".skillhub_write_test_{}", std::process::id()));
+    let ok = fs::write(&probe, b"").is_ok();
+    let _ = fs::remove_file(&probe);
+    ok
+}
+
+fn check_skills_dirs(report: &mut DoctorReport) {
+    for spec in all_platforms() {
+        let platform = &spec.label;
+        let Ok(dir) = spec.global_skills_dir() else {
+            continue;
+        };
+        if !is_writable(&dir) {
+            report.push(
+                "skills_dir_writable",
+                FindingLevel::Error,
+                format!("{platform} 的 skills 目录不可写"),
+                Some(&dir),
+                Some("检查该目录的所有者与权限，或以当前用户重新创建该目录"),
+            );
+            continue;
+        }
+        report.push(
+            "skills_dir_writable",
+            FindingLevel::Ok,
+            format!("{platform} 的 skills 目录可写"),
+            Some(&dir),
+            None,
+        );
+
+        let Ok(rd) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for ent in rd.flatten() {
+            let path = ent.path();
+            let is_symlink = fs::symlink_metadata(&path).map(|m| m.file_type().is_symlink()).unwrap_or(false);
+            if is_symlink && fs::metadata(&path).is_err() {
+                report.push(
+                    "broken_symlink",
+                    FindingLevel::Warning,
+                    format!("{platform} 下的符号链接已失效"),
+                    Some(&path),
+                    Some("删除该链接，或重新安装对应 skill"),
+                );
+                continue;
+            }
+            if path.is_dir() && find_skill_md(&path, 3).is_none() {
+                report.push(
+                    "missing_skill_md",
+                    FindingLevel::Warning,
+                    format!("{platform} 下的 skill 缺少 SKILL.md，agent 将无法加载"),
+                    Some(&path),
+                    Some("重新安装该 skill，或手动补充 SKILL.md"),
+                );
+            }
+        }
+    }
+}
+
+fn check_temp_dirs(report: &mut DoctorReport) {
+    let tmp = std::env::temp_dir();
+    let Ok(rd) = fs::read_dir(&tmp) else {
+        return;
+    };
+    let prefix = format!("{CLONE_TEMP_PREFIX}_");
+    for ent in rd.flatten() {
+        if ent.file_name().to_string_lossy().starts_with(&prefix) {
+            report.push(
+                "leftover_temp_dir",
+                FindingLevel::Warning,
+                "残留的安装临时目录（上次安装可能被中断）".into(),
+                Some(&ent.path()),
+                Some("确认没有正在进行的安装后删除该目录"),
+            );
+        }
+    }
+}
+
+fn check_watcher(report: &mut DoctorReport) {
+    if let Some(e) = watcher_error() {
+        report.push(
+            "skills_watcher",
+            FindingLevel::Warning,
+            format!("skills 目录监听未启动，列表不会自动刷新: {e}"),
+            None,
+            Some("检查目录权限或系统的文件监听数量上限（如 Linux 的 fs.inotify.max_user_watches）后重新建立监听"),
+        );
+    }
+}
+
+/// 环境体检：git / node / skills CLI、skills 目录可写性、残留临时目录、目录监听、失效链接与缺失的 SKILL.md
+#[tauri::command]
+pub async fn run_doctor() -> CommandResult<DoctorReport> {
+    // git / node / npx / skills --version 均为阻塞的子进程调用
+    run_blocking(|| {
+        let mut report = DoctorReport::default();
+        check_git(&mut report);
+        check_node(&mut report);
+        check_skills_dirs(&mut report);
+        check_temp_dirs(&mut report);
+        check_watcher(&mut report);
+        Ok(report)
+    })
+    .await
+}
@@ -0,0 +1,110 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::files::{resolve_in_skill, skill_root};
+use crate::commands::frontmatter::{parse_skill_md, FrontmatterDiagnostic};
+use crate::commands::git::ensure_dir;
+use crate::commands::record::{now_secs, read_record, write_record, RECORD_FILE};
+use crate::commands::settings::skillhub_dir;
+use crate::commands::toggle::project_key;
+
+/// 单次写入的内容上限（字节）
+const MAX_WRITE_BYTES: usize = 2 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WriteSkillFileResult {
+    pub path: String,
+    /// 覆盖前内容的备份；新建文件时为 None
+    pub backup_path: Option<String>,
+    /// 写入的是 SKILL.md 时，保存后重新解析 frontmatter 的结果
+    pub frontmatter_diagnostics: Vec<FrontmatterDiagnostic>,
+    /// 已在安装记录中标记为本地修改（无安装记录的 skill 为 false）
+    pub marked_modified: bool,
+}
+
+/// 备份目录：~/.skillhub/backups/<skill 目录标识>/<毫秒时间戳>/<相对路径>
+fn backup_path(root: &Path, rel: &Path) -> CommandResult<PathBuf> {
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    Ok(skillhub_dir()?.join("backups").join(project_key(root)).join(millis.to_string()).join(rel))
+}
+
+/// 先写同目录下的临时文件并落盘，再 rename 覆盖目标，避免写到一半留下残缺文件
+fn write_atomic(path: &Path, content: &[u8]) -> CommandResult<()> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let file_name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let tmp = dir.join(format!(".{file_name}.skillhub-{}.tmp", std::process::id()));
+    let result = (|| {
+        let mut f = fs::File::create(&tmp).map_err(|e| CommandError::io("创建临时文件", &tmp, e))?;
+        f.write_all(content).map_err(|e| CommandError::io("写入文件", &tmp, e))?;
+        f.sync_all().map_err(|e| CommandError::io("写入文件", &tmp, e))?;
+        if let Ok(meta) = fs::metadata(path) {
+            // 保留原文件权限（如脚本的可执行位）
+            let _ = fs::set_permissions(&tmp, meta.permissions());
+        }
+        fs::rename(&tmp, path).map_err(|e| CommandError::io("替换文件", path, e))
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// 写入已安装 skill 内的文件（路径限制在 skill 目录内）：原子替换、保留带时间戳的备份、SKILL.md 保存后重新校验 frontmatter，
+/// 并在安装记录中标记本地修改，之后的修复不会静默覆盖
+#[tauri::command]
+pub fn write_skill_file(install_path: String, path: String, content: String) -> CommandResult<WriteSkillFileResult> {
+    if content.len() > MAX_WRITE_BYTES {
+        return Err(CommandError::invalid("content", format!("超过 {} KB 上限", MAX_WRITE_BYTES / 1024)));
+    }
+    let root = skill_root(&install_path)?;
+    let file = resolve_in_skill(&root, &path)?;
+    let rel = file.strip_prefix(&root).unwrap_or(&file).to_path_buf();
+    if rel == Path::new(RECORD_FILE) || rel.starts_with(".git") {
+        return Err(CommandError::PathNotAllowed { path: file.display().to_string() });
+    }
+    if file.is_dir() {
+        return Err(CommandError::invalid("path", "不能是目录"));
+    }
+
+    let backup = if file.is_file() {
+        let backup = backup_path(&root, &rel)?;
+        if let Some(parent) = backup.parent() {
+            ensure_dir(parent)?;
+        }
+        fs::copy(&file, &backup).map_err(|e| CommandError::io("备份文件", &file, e))?;
+        Some(backup)
+    } else {
+        None
+    };
+    write_atomic(&file, content.as_bytes())?;
+
+    let frontmatter_diagnostics = if rel == Path::new("SKILL.md") {
+        let saved = fs::read_to_string(&file).map_err(|e| CommandError::io("读取文件", &file, e))?;
+        parse_skill_md(&saved).diagnostics
+    } else {
+        vec![]
+    };
+
+    let marked_modified = match read_record(&root) {
+        Some(mut record) => {
+            record.local_modified_at = Some(now_secs());
+            write_record(&root, &record)?;
+            true
+        }
+        None => false,
+    };
+
+    Ok(WriteSkillFileResult {
+        path: rel.to_string_lossy().replace('\\', "/"),
+        backup_path: backup.map(|p| p.display().to_string()),
+        frontmatter_diagnostics,
+        marked_modified,
+    })
+}
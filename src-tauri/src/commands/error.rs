@@ -0,0 +1,161 @@
+use std::fmt;
+use std::path::Path;
+
+use serde::{Serialize, Serializer};
+
+use crate::commands::limits::LimitKind;
+use crate::commands::retry::{format_history, AttemptRecord};
+use crate::commands::scan::Severity;
+
+/// stderr 末尾保留的最大行数
+const STDERR_TAIL_LINES: usize = 20;
+
+/// 后端命令统一错误类型。
+///
+/// 序列化为 `{ code, message, details }`：`code` 为稳定的错误码，前端据此分支处理；
+/// `message` 为给用户看的说明；`details` 为结构化字段（路径、命令、退出码等）。
+#[derive(Debug, Clone, Serialize)]
+#[serde(remote = "Self", tag = "code", content = "details", rename_all = "snake_case")]
+pub enum CommandError {
+    /// 参数不合法
+    InvalidInput { field: String, reason: String },
+    /// 不支持的平台
+    UnsupportedPlatform { platform: String },
+    /// 无法定位用户目录
+    HomeNotFound,
+    /// 路径不存在
+    NotFound { path: String },
+    /// 目标已存在（疑似已安装）
+    AlreadyInstalled { path: String },
+    /// 路径不在允许操作的 skills 目录内
+    PathNotAllowed { path: String },
+    /// 文件系统操作失败
+    Io { operation: String, path: String, reason: String },
+    /// 未安装 git
+    GitMissing,
+    /// 未安装 Node.js / npx
+    NpxMissing,
+    /// 外部命令执行失败
+    CommandFailed {
+        command: String,
+        exit_code: Option<i32>,
+        stderr_tail: String,
+        /// 经过重试时的完整尝试记录
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        attempts: Vec<AttemptRecord>,
+    },
+    /// 超出安装限额
+    LimitExceeded { limit: LimitKind, path: Option<String>, actual: u64, max: u64 },
+    /// 包含禁止的文件类型
+    DeniedFileType { extension: String, path: String },
+    /// 安全扫描命中阈值，需用户确认
+    SecurityReviewRequired { highest: Severity, findings: usize, summary: String },
+    /// skill 已在本地编辑过，覆盖前需确认
+    LocallyModified { path: String, modified_at: u64 },
+    /// 许可证策略拒绝
+    LicenseRejected { spdx: Option<String>, reason: String },
+    /// HTTP 请求失败（status 为 None 表示未收到响应）
+    HttpFailed { url: String, status: Option<u16>, reason: String },
+    /// 数据序列化 / 反序列化失败
+    Serialization { reason: String },
+    /// 操作失败后回滚未能完成，部分改动仍保留（error 为最初的失败原因）
+    RollbackIncomplete { error: Box<CommandError>, rollback_failures: Vec<String> },
+}
+
+pub type CommandResult<T> = Result<T, CommandError>;
+
+impl CommandError {
+    pub fn invalid(field: &str, reason: impl Into<String>) -> Self {
+        Self::InvalidInput { field: field.to_string(), reason: reason.into() }
+    }
+
+    pub fn io(operation: &str, path: &Path, err: impl fmt::Display) -> Self {
+        Self::Io {
+            operation: operation.to_string(),
+            path: path.display().to_string(),
+            reason: err.to_string(),
+        }
+    }
+
+    pub fn not_found(path: &Path) -> Self {
+        Self::NotFound { path: path.display().to_string() }
+    }
+
+    /// 由命令输出构造 CommandFailed，仅保留 stderr（为空时取 stdout）末尾若干行
+    pub fn command_failed(command: String, exit_code: Option<i32>, stdout: &str, stderr: &str) -> Self {
+        let source = if stderr.trim().is_empty() { stdout } else { stderr };
+        let lines: Vec<&str> = source.lines().filter(|l| !l.trim().is_empty()).collect();
+        let tail = lines[lines.len().saturating_sub(STDERR_TAIL_LINES)..].join("\n");
+        Self::CommandFailed { command, exit_code, stderr_tail: tail, attempts: vec![] }
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInput { field, reason } => write!(f, "{field} {reason}"),
+            Self::UnsupportedPlatform { platform } => write!(f, "不支持的平台: {platform}"),
+            Self::HomeNotFound => write!(f, "无法定位用户目录（USERPROFILE/HOME）"),
+            Self::NotFound { path } => write!(f, "路径不存在，可能已被删除: {path}"),
+            Self::AlreadyInstalled { path } => write!(f, "已存在同名目录，疑似已安装: {path}"),
+            Self::PathNotAllowed { path } => write!(f, "路径不在合法的 skills 目录下: {path}"),
+            Self::Io { operation, path, reason } => write!(f, "{operation}失败 {path}: {reason}"),
+            Self::GitMissing => write!(f, "未检测到 git，请先安装 Git 并确保其在 PATH 中"),
+            Self::NpxMissing => write!(f, "执行 npx 失败，请确保已安装 Node.js"),
+            Self::CommandFailed { command, exit_code, stderr_tail, attempts } => {
+                write!(f, "命令执行失败: {command}")?;
+                if let Some(code) = exit_code {
+                    write!(f, "（退出码 {code}）")?;
+                }
+                if !stderr_tail.is_empty() {
+                    write!(f, "\n{stderr_tail}")?;
+                }
+                write!(f, "{}", format_history(attempts))
+            }
+            Self::LimitExceeded { limit, path, actual, max } => {
+                write!(f, "超出安装限制：{}（实际 {actual}，上限 {max}）", limit.label())?;
+                if let Some(p) = path {
+                    write!(f, " {p}")?;
+                }
+                Ok(())
+            }
+            Self::DeniedFileType { extension, path } => {
+                write!(f, "超出安装限制：包含禁止的文件类型 .{extension}（{path}）")
+            }
+            Self::SecurityReviewRequired { highest, findings, summary } => write!(
+                f,
+                "安全扫描发现 {findings} 处风险（最高 {highest:?}），需确认接受风险后再安装:\n{summary}"
+            ),
+            Self::LocallyModified { path, .. } => {
+                write!(f, "skill 已在本地修改，确认覆盖后才能恢复为来源版本: {path}")
+            }
+            Self::LicenseRejected { reason, .. } => write!(f, "许可证检查未通过: {reason}"),
+            Self::HttpFailed { url, reason, .. } => write!(f, "请求失败 {url}: {reason}"),
+            Self::Serialization { reason } => write!(f, "数据序列化失败: {reason}"),
+            Self::RollbackIncomplete { error, rollback_failures } => {
+                write!(f, "{error}\n回滚未完成，以下改动未能撤销:")?;
+                for failure in rollback_failures {
+                    write!(f, "\n- {failure}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl Serialize for CommandError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Wire<'a> {
+            #[serde(flatten, serialize_with = "serialize_tagged")]
+            error: &'a CommandError,
+            message: String,
+        }
+        fn serialize_tagged<S: Serializer>(e: &&CommandError, s: S) -> Result<S::Ok, S::Error> {
+            CommandError::serialize(e, s)
+        }
+        Wire { error: self, message: self.to_string() }.serialize(serializer)
+    }
+}
@@ -0,0 +1,263 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use zip::write::SimpleFileOptions;
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::files::skill_root;
+use crate::commands::frontmatter::parse_skill_md;
+use crate::commands::license::{detect_license, LicenseInfo};
+use crate::commands::record::{now_secs, read_record, RECORD_FILE};
+use crate::commands::retry::run_blocking;
+
+/// 压缩包根目录下的清单文件名
+pub const MANIFEST_FILE: &str = "skillhub-manifest.json";
+
+/// 不导出的版本控制目录
+const VCS_DIRS: &[&str] = &[".git", ".svn", ".hg"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Zip,
+    TarGz,
+}
+
+impl ExportFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+/// 来源信息（取自安装记录）
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportSource {
+    pub repo: String,
+    pub sub_path: Option<String>,
+    pub commit: Option<String>,
+    pub method: String,
+    pub installed_at: u64,
+    /// 导出的内容包含在 SkillHub 内的本地修改
+    pub local_modified_at: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestFile {
+    /// 相对 skill 目录的路径
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// 导出清单：随压缩包分发，接收方可据此核对来源与内容
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportManifest {
+    pub manifest_version: u32,
+    pub id: String,
+    pub exported_at: u64,
+    /// 无安装记录（手动放置或本地新建）时为 None
+    pub source: Option<ExportSource>,
+    pub license: LicenseInfo,
+    pub frontmatter: Option<Map<String, Value>>,
+    pub files: Vec<ManifestFile>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportResult {
+    pub path: String,
+    pub format: ExportFormat,
+    pub bytes: u64,
+    pub manifest: ExportManifest,
+}
+
+/// 待打包的文件：内容只读取一次，清单哈希与压缩包条目使用同一份数据
+struct PackedFile {
+    rel: String,
+    path: PathBuf,
+    mode: u32,
+    mtime: u64,
+    data: Vec<u8>,
+}
+
+#[cfg(unix)]
+fn file_mode(meta: &fs::Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    meta.permissions().mode() & 0o777
+}
+
+#[cfg(not(unix))]
+fn file_mode(_meta: &fs::Metadata) -> u32 {
+    0o644
+}
+
+/// 收集导出文件：跳过版本控制目录、安装记录与写入时的临时文件；符号链接仅保留指向 skill 目录内的文件
+fn collect(root: &Path, dir: &Path, out: &mut Vec<PackedFile>) {
+    let Ok(rd) = fs::read_dir(dir) else {
+        return;
+    };
+    for ent in rd.flatten() {
+        let name = ent.file_name().to_string_lossy().to_string();
+        let path = ent.path();
+        let Ok(link_meta) = fs::symlink_metadata(&path) else {
+            continue;
+        };
+        if link_meta.is_dir() {
+            if !VCS_DIRS.contains(&name.as_str()) {
+                collect(root, &path, out);
+            }
+            continue;
+        }
+        if dir == root && name == RECORD_FILE {
+            continue;
+        }
+        if name.starts_with('.') && name.contains(".skillhub-") && name.ends_with(".tmp") {
+            continue;
+        }
+        if link_meta.file_type().is_symlink() && !path.canonicalize().is_ok_and(|p| p.starts_with(root)) {
+            continue;
+        }
+        let Ok(meta) = fs::metadata(&path) else {
+            continue;
+        };
+        if !meta.is_file() {
+            continue;
+        }
+        let mtime = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        out.push(PackedFile {
+            rel: path.strip_prefix(root).unwrap_or(&path).to_string_lossy().replace('\\', "/"),
+            mode: file_mode(&meta),
+            mtime,
+            path,
+            data: vec![],
+        });
+    }
+}
+
+fn write_zip(dest: &Path, id: &str, files: &[PackedFile], manifest: &[u8]) -> CommandResult<()> {
+    let file = fs::File::create(dest).map_err(|e| CommandError::io("创建文件", dest, e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let zip_err = |e: zip::result::ZipError| CommandError::io("写入压缩包", dest, e);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    zip.start_file(MANIFEST_FILE, options.unix_permissions(0o644)).map_err(zip_err)?;
+    zip.write_all(manifest).map_err(|e| CommandError::io("写入压缩包", dest, e))?;
+    for f in files {
+        zip.start_file(format!("{id}/{}", f.rel), options.unix_permissions(f.mode)).map_err(zip_err)?;
+        zip.write_all(&f.data).map_err(|e| CommandError::io("写入压缩包", dest, e))?;
+    }
+    zip.finish().map_err(zip_err)?;
+    Ok(())
+}
+
+fn write_tar_gz(dest: &Path, id: &str, files: &[PackedFile], manifest: &[u8]) -> CommandResult<()> {
+    let file = fs::File::create(dest).map_err(|e| CommandError::io("创建文件", dest, e))?;
+    let mut tar = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+    let io_err = |e: std::io::Error| CommandError::io("写入压缩包", dest, e);
+    let mut append = |name: &str, data: &[u8], mode: u32, mtime: u64| {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(mode);
+        header.set_mtime(mtime);
+        tar.append_data(&mut header, name, data)
+    };
+
+    append(MANIFEST_FILE, manifest, 0o644, now_secs()).map_err(io_err)?;
+    for f in files {
+        append(&format!("{id}/{}", f.rel), &f.data, f.mode, f.mtime).map_err(io_err)?;
+    }
+    tar.into_inner().and_then(|gz| gz.finish()).map_err(io_err)?;
+    Ok(())
+}
+
+/// 将已安装 skill 打包为 zip 或 tar.gz：压缩包内为 `<id>/` 目录加根目录下的清单（来源、内容哈希、frontmatter、许可证），
+/// 不含 .git 等版本控制目录与 SkillHub 安装记录。format 为空时按 dest 扩展名判断。
+#[tauri::command]
+pub async fn export_skill(install_path: String, dest: String, format: Option<ExportFormat>) -> CommandResult<ExportResult> {
+    run_blocking(move || export(&install_path, &dest, format)).await
+}
+
+fn export(install_path: &str, dest: &str, format: Option<ExportFormat>) -> CommandResult<ExportResult> {
+    let root = skill_root(install_path)?;
+    let dest = PathBuf::from(dest.trim());
+    if !dest.is_absolute() {
+        return Err(CommandError::invalid("dest", "必须是绝对路径"));
+    }
+    if dest.starts_with(&root) {
+        return Err(CommandError::invalid("dest", "不能位于 skill 目录内"));
+    }
+    let format = format
+        .or_else(|| ExportFormat::from_path(&dest))
+        .ok_or_else(|| CommandError::invalid("dest", "无法从扩展名判断格式，请使用 .zip 或 .tar.gz"))?;
+    let id = root.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+
+    let mut files = vec![];
+    collect(&root, &root, &mut files);
+    files.sort_by(|a, b| a.rel.cmp(&b.rel));
+
+    let mut manifest_files = Vec::with_capacity(files.len());
+    for f in &mut files {
+        f.data = fs::read(&f.path).map_err(|e| CommandError::io("读取文件", &f.path, e))?;
+        manifest_files.push(ManifestFile {
+            path: f.rel.clone(),
+            size: f.data.len() as u64,
+            sha256: format!("{:x}", Sha256::digest(&f.data)),
+        });
+    }
+    let record = read_record(&root);
+    let frontmatter = fs::read_to_string(root.join("SKILL.md")).ok().and_then(|md| parse_skill_md(&md).fields);
+    let manifest = ExportManifest {
+        manifest_version: 1,
+        id: id.clone(),
+        exported_at: now_secs(),
+        license: record
+            .as_ref()
+            .and_then(|r| r.license.clone())
+            .unwrap_or_else(|| detect_license(&root, None)),
+        source: record.map(|r| ExportSource {
+            repo: r.repo,
+            sub_path: r.sub_path,
+            commit: r.commit,
+            method: r.method,
+            installed_at: r.installed_at,
+            local_modified_at: r.local_modified_at,
+        }),
+        frontmatter,
+        files: manifest_files,
+    };
+    let manifest_json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| CommandError::Serialization { reason: e.to_string() })?;
+
+    // 先写临时文件，完成后再替换目标，避免留下残缺的压缩包
+    let file_name = dest.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let tmp = dest.with_file_name(format!(".{file_name}.skillhub-{}.tmp", std::process::id()));
+    let written = match format {
+        ExportFormat::Zip => write_zip(&tmp, &id, &files, &manifest_json),
+        ExportFormat::TarGz => write_tar_gz(&tmp, &id, &files, &manifest_json),
+    }
+    .and_then(|_| fs::rename(&tmp, &dest).map_err(|e| CommandError::io("写入文件", &dest, e)));
+    if let Err(e) = written {
+        let _ = fs::remove_file(&tmp);
+        return Err(e);
+    }
+
+    let bytes = fs::metadata(&dest).map(|m| m.len()).unwrap_or(0);
+    Ok(ExportResult { path: dest.display().to_string(), format, bytes, manifest })
+}
@@ -0,0 +1,232 @@
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::fs::home_dir;
+use crate::commands::platforms::{all_platforms, PlatformFeature};
+use crate::commands::toggle::disabled_root;
+
+/// 文件树最多返回的条目数
+const MAX_TREE_ENTRIES: usize = 5000;
+/// 读取文件的默认上限与允许的最大上限（字节）
+const DEFAULT_READ_BYTES: u64 = 1024 * 1024;
+const MAX_READ_BYTES: u64 = 8 * 1024 * 1024;
+/// 检测二进制内容时检查的前缀长度
+const BINARY_SNIFF_BYTES: usize = 8192;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillFileKind {
+    File,
+    Dir,
+    /// 设备、套接字或指向不存在目标的符号链接等
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillFileEntry {
+    /// 相对 skill 根目录的路径，使用 `/` 分隔
+    pub path: String,
+    /// 符号链接按其目标的类型给出
+    pub kind: SkillFileKind,
+    pub size: u64,
+    pub is_symlink: bool,
+    pub symlink_target: Option<String>,
+    /// 符号链接指向 skill 目录之外（读取时会被拒绝）
+    pub outside_root: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillFileTree {
+    pub root: String,
+    /// 先目录后文件、按路径排序；不包含 .git
+    pub entries: Vec<SkillFileEntry>,
+    /// 条目数超过上限被截断
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillFileContent {
+    pub path: String,
+    pub size: u64,
+    /// 二进制文件不返回内容
+    pub binary: bool,
+    /// 文件超过读取上限，只返回了开头部分
+    pub truncated: bool,
+    pub content: Option<String>,
+}
+
+/// 规范化后的目录是否为已安装的 skill：必须是某个 skills 目录的直接子目录——平台全局目录、
+/// 项目级目录（<project>/<project_dir>/<id>）或停用区（disabled/<platform>/global|projects/<项目标识>）
+pub(crate) fn is_installed_skill_dir(canonical: &Path) -> bool {
+    let Some(parent) = canonical.parent() else {
+        return false;
+    };
+    if home_dir().is_ok_and(|h| h.canonicalize().unwrap_or(h) == canonical) {
+        return false;
+    }
+    let platforms = all_platforms();
+    let in_global = platforms
+        .iter()
+        .filter_map(|p| p.global_skills_dir().ok()?.canonicalize().ok())
+        .any(|dir| dir == parent);
+    if in_global {
+        return true;
+    }
+    let in_project = platforms.iter().filter(|p| p.supports(PlatformFeature::ProjectInstall)).any(|p| {
+        let suffix: PathBuf = p.project_dir.split('/').filter(|s| !s.is_empty()).collect();
+        parent.ends_with(&suffix) && parent.components().count() > suffix.components().count()
+    });
+    if in_project {
+        return true;
+    }
+    let Some(disabled) = disabled_root().ok().and_then(|d| d.canonicalize().ok()) else {
+        return false;
+    };
+    let Ok(rel) = parent.strip_prefix(&disabled) else {
+        return false;
+    };
+    // rel 为 <platform>/global 或 <platform>/projects/<项目标识>
+    let mut parts = rel.components().skip(1).map(|c| c.as_os_str());
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(kind), None, None) => kind == "global",
+        (Some(kind), Some(_), None) => kind == "projects",
+        _ => false,
+    }
+}
+
+/// 校验并规范化 skill 根目录：解析符号链接后须为已安装的 skill 目录，拒绝主目录等任意位置
+pub(crate) fn skill_root(install_path: &str) -> CommandResult<PathBuf> {
+    let path = Path::new(install_path.trim());
+    if install_path.trim().is_empty() {
+        return Err(CommandError::invalid("install_path", "不能为空"));
+    }
+    if !path.is_dir() {
+        return Err(CommandError::not_found(path));
+    }
+    let canonical = path.canonicalize().map_err(|e| CommandError::io("解析路径", path, e))?;
+    if !is_installed_skill_dir(&canonical) {
+        return Err(CommandError::PathNotAllowed { path: install_path.to_string() });
+    }
+    Ok(canonical)
+}
+
+/// 将相对路径解析到 skill 目录内：拒绝绝对路径和 `..`，并在规范化（解析符号链接）后确认仍位于根目录下。
+/// 目标不存在时以其已存在的父目录为准，便于写入新文件。
+pub(crate) fn resolve_in_skill(root: &Path, rel_path: &str) -> CommandResult<PathBuf> {
+    let rel = Path::new(rel_path.trim());
+    if rel.as_os_str().is_empty() || !rel.components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err(CommandError::invalid("path", "必须是 skill 目录内的相对路径"));
+    }
+    let joined = root.join(rel);
+    let resolved = match joined.canonicalize() {
+        Ok(p) => p,
+        Err(_) => {
+            let parent = joined.parent().unwrap_or(root);
+            let file_name = joined.file_name().unwrap_or_default();
+            parent
+                .canonicalize()
+                .map_err(|_| CommandError::not_found(&joined))?
+                .join(file_name)
+        }
+    };
+    if !resolved.starts_with(root) {
+        return Err(CommandError::PathNotAllowed { path: joined.display().to_string() });
+    }
+    Ok(resolved)
+}
+
+fn looks_binary(bytes: &[u8]) -> bool {
+    bytes.iter().take(BINARY_SNIFF_BYTES).any(|b| *b == 0)
+}
+
+fn walk(root: &Path, dir: &Path, tree: &mut SkillFileTree) {
+    let Ok(rd) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut children: Vec<_> = rd.flatten().filter(|e| e.file_name() != ".git").collect();
+    children.sort_by_key(|e| e.file_name());
+    for ent in children {
+        if tree.entries.len() >= MAX_TREE_ENTRIES {
+            tree.truncated = true;
+            return;
+        }
+        let path = ent.path();
+        let Ok(link_meta) = fs::symlink_metadata(&path) else {
+            continue;
+        };
+        let is_symlink = link_meta.file_type().is_symlink();
+        // 符号链接按目标类型展示，但不进入链接的目录，避免循环和越界
+        let meta = if is_symlink { fs::metadata(&path).ok() } else { Some(link_meta) };
+        let kind = match &meta {
+            Some(m) if m.is_dir() => SkillFileKind::Dir,
+            Some(m) if m.is_file() => SkillFileKind::File,
+            _ => SkillFileKind::Other,
+        };
+        let outside_root = is_symlink && !path.canonicalize().is_ok_and(|p| p.starts_with(root));
+        tree.entries.push(SkillFileEntry {
+            path: path.strip_prefix(root).unwrap_or(&path).to_string_lossy().replace('\\', "/"),
+            kind,
+            size: if kind == SkillFileKind::File { meta.map(|m| m.len()).unwrap_or(0) } else { 0 },
+            is_symlink,
+            symlink_target: is_symlink
+                .then(|| fs::read_link(&path).ok().map(|t| t.display().to_string()))
+                .flatten(),
+            outside_root,
+        });
+        if kind == SkillFileKind::Dir && !is_symlink {
+            walk(root, &path, tree);
+        }
+    }
+}
+
+/// 列出已安装 skill 的文件树（大小、类型、符号链接）
+#[tauri::command]
+pub fn list_skill_files(install_path: String) -> CommandResult<SkillFileTree> {
+    let root = skill_root(&install_path)?;
+    let mut tree = SkillFileTree { root: root.display().to_string(), entries: vec![], truncated: false };
+    walk(&root, &root, &mut tree);
+    tree.entries.sort_by(|a, b| {
+        (a.kind != SkillFileKind::Dir).cmp(&(b.kind != SkillFileKind::Dir)).then_with(|| a.path.cmp(&b.path))
+    });
+    Ok(tree)
+}
+
+/// 读取已安装 skill 内的单个文件；路径限制在 skill 目录内，超过上限时截断，二进制文件不返回内容
+#[tauri::command]
+pub fn read_skill_file(install_path: String, path: String, max_bytes: Option<u64>) -> CommandResult<SkillFileContent> {
+    let root = skill_root(&install_path)?;
+    let file = resolve_in_skill(&root, &path)?;
+    if !file.is_file() {
+        return Err(CommandError::not_found(&file));
+    }
+    let size = fs::metadata(&file).map_err(|e| CommandError::io("读取文件信息", &file, e))?.len();
+    let cap = max_bytes.unwrap_or(DEFAULT_READ_BYTES).clamp(1, MAX_READ_BYTES);
+
+    let mut bytes = Vec::with_capacity(size.min(cap) as usize);
+    fs::File::open(&file)
+        .and_then(|f| f.take(cap).read_to_end(&mut bytes))
+        .map_err(|e| CommandError::io("读取文件", &file, e))?;
+    let truncated = size > cap;
+    let rel = path.trim().replace('\\', "/");
+
+    let content = if looks_binary(&bytes) {
+        None
+    } else {
+        match String::from_utf8(bytes) {
+            Ok(text) => Some(text),
+            // 截断处切断了多字节字符：丢弃末尾不完整的部分
+            Err(e) if truncated && e.utf8_error().error_len().is_none() => {
+                let valid = e.utf8_error().valid_up_to();
+                let mut bytes = e.into_bytes();
+                bytes.truncate(valid);
+                String::from_utf8(bytes).ok()
+            }
+            Err(_) => None,
+        }
+    };
+    Ok(SkillFileContent { path: rel, size, binary: content.is_none(), truncated, content })
+}
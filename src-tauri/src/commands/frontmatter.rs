@@ -0,0 +1,201 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// 诊断级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// frontmatter 解析诊断
+#[derive(Debug, Clone, Serialize)]
+pub struct FrontmatterDiagnostic {
+    pub severity: DiagnosticSeverity,
+    /// SKILL.md 中的行号（从 1 开始）
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+/// SKILL.md 解析结果
+#[derive(Debug, Clone, Default)]
+pub struct ParsedSkillMd {
+    /// frontmatter 的完整结构（YAML 映射转为 JSON 对象）；缺失或解析失败时为 None
+    pub fields: Option<Map<String, Value>>,
+    /// frontmatter 原文（不含 `---` 分隔行）
+    pub yaml: Option<String>,
+    pub diagnostics: Vec<FrontmatterDiagnostic>,
+    /// frontmatter 之后的正文
+    pub body: String,
+    /// 正文首行在文件中的行号（从 1 开始）
+    pub body_line: usize,
+    /// frontmatter 各顶层键所在行号
+    pub key_lines: Vec<(String, usize)>,
+}
+
+impl ParsedSkillMd {
+    fn diag(&mut self, severity: DiagnosticSeverity, line: Option<usize>, message: impl Into<String>) {
+        self.diagnostics.push(FrontmatterDiagnostic { severity, line, message: message.into() });
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.fields.as_ref()?.get(key)
+    }
+
+    /// 字符串字段（数字 / 布尔值转为字符串），空白时为 None
+    pub fn str_field(&self, key: &str) -> Option<String> {
+        let s = match self.get(key)? {
+            Value::String(s) => s.trim().to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::Bool(b) => b.to_string(),
+            _ => return None,
+        };
+        (!s.is_empty()).then_some(s)
+    }
+
+    /// 字符串列表字段：支持 YAML 列表，也兼容 `a, b` 形式的字符串
+    pub fn list_field(&self, key: &str) -> Vec<String> {
+        match self.get(key) {
+            Some(Value::Array(items)) => items
+                .iter()
+                .filter_map(|v| match v {
+                    Value::String(s) => Some(s.trim().to_string()),
+                    Value::Number(n) => Some(n.to_string()),
+                    _ => None,
+                })
+                .filter(|s| !s.is_empty())
+                .collect(),
+            Some(Value::String(s)) => s
+                .split(',')
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// 顶层键所在行号
+    pub fn key_line(&self, key: &str) -> Option<usize> {
+        self.key_lines.iter().find(|(k, _)| k == key).map(|(_, l)| *l)
+    }
+}
+
+fn is_delimiter(line: &str) -> bool {
+    let t = line.trim_end();
+    t == "---" || t == "..."
+}
+
+/// 解析 SKILL.md：以 `---` 开头、以单独一行 `---` 结束的 YAML frontmatter + Markdown 正文
+pub(crate) fn parse_skill_md(md: &str) -> ParsedSkillMd {
+    let md = md.replace("\r\n", "\n");
+    let md = md.strip_prefix('\u{feff}').unwrap_or(&md);
+    let lines: Vec<&str> = md.split('\n').collect();
+    let mut parsed = ParsedSkillMd { body: md.to_string(), body_line: 1, ..Default::default() };
+
+    if lines.first().map(|l| l.trim_end()) != Some("---") {
+        parsed.diag(DiagnosticSeverity::Warning, Some(1), "缺少 YAML frontmatter（文件应以 --- 开头）");
+        return parsed;
+    }
+    let Some(end) = lines.iter().skip(1).position(|l| is_delimiter(l)).map(|i| i + 1) else {
+        parsed.diag(DiagnosticSeverity::Error, Some(1), "frontmatter 未闭合（缺少结束的 ---）");
+        return parsed;
+    };
+
+    let yaml = lines[1..end].join("\n");
+    parsed.yaml = Some(yaml.clone());
+    parsed.body = lines[end + 1..].join("\n");
+    parsed.body_line = end + 2;
+    // 顶层键：不缩进、非注释、含冒号的行
+    parsed.key_lines = lines[1..end]
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| !l.starts_with([' ', '\t', '#', '-']))
+        .filter_map(|(i, l)| {
+            let key = l.split_once(':')?.0.trim().trim_matches('"').trim_matches('\'');
+            (!key.is_empty()).then(|| (key.to_string(), i + 2))
+        })
+        .collect();
+
+    if yaml.trim().is_empty() {
+        parsed.diag(DiagnosticSeverity::Warning, Some(1), "frontmatter 为空");
+        parsed.fields = Some(Map::new());
+        return parsed;
+    }
+
+    let value: serde_yaml::Value = match serde_yaml::from_str(&yaml) {
+        Ok(v) => v,
+        Err(e) => {
+            // 行号换算到 SKILL.md：frontmatter 从第 2 行开始
+            let line = e.location().map(|loc| loc.line() + 1);
+            // serde_yaml 的错误信息自带相对 frontmatter 的位置，已换算到 line 字段，这里去掉
+            let reason = e.to_string();
+            let reason = reason.split(" at line ").next().unwrap_or(&reason).to_string();
+            parsed.diag(DiagnosticSeverity::Error, line, format!("frontmatter YAML 解析失败: {reason}"));
+            return parsed;
+        }
+    };
+    match serde_json::to_value(&value) {
+        Ok(Value::Object(map)) => parsed.fields = Some(map),
+        Ok(_) => parsed.diag(DiagnosticSeverity::Error, Some(2), "frontmatter 顶层必须是键值映射"),
+        Err(e) => parsed.diag(DiagnosticSeverity::Error, Some(2), format!("frontmatter 含无法表示的值（如非字符串键）: {e}")),
+    }
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_fields_body_and_key_lines() {
+        let md = "---\nname: demo\ndescription: \"A demo\"\ntags: [a, b]\nversion: 2\n---\n# Title\nbody\n";
+        let parsed = parse_skill_md(md);
+        assert!(parsed.diagnostics.is_empty());
+        assert_eq!(parsed.str_field("name").as_deref(), Some("demo"));
+        assert_eq!(parsed.str_field("version").as_deref(), Some("2"));
+        assert_eq!(parsed.list_field("tags"), vec!["a", "b"]);
+        assert_eq!(parsed.key_line("description"), Some(3));
+        assert_eq!(parsed.body, "# Title\nbody\n");
+        assert_eq!(parsed.body_line, 7);
+    }
+
+    #[test]
+    fn handles_crlf_and_bom() {
+        let parsed = parse_skill_md("\u{feff}---\r\nname: demo\r\n---\r\nbody");
+        assert_eq!(parsed.str_field("name").as_deref(), Some("demo"));
+        assert_eq!(parsed.body, "body");
+    }
+
+    #[test]
+    fn list_field_accepts_comma_separated_string() {
+        let parsed = parse_skill_md("---\ntags: a, b ,, c\n---\n");
+        assert_eq!(parsed.list_field("tags"), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn missing_frontmatter_keeps_whole_file_as_body() {
+        let parsed = parse_skill_md("# Title\n");
+        assert!(parsed.fields.is_none());
+        assert_eq!(parsed.body, "# Title\n");
+        assert_eq!(parsed.diagnostics[0].severity, DiagnosticSeverity::Warning);
+    }
+
+    #[test]
+    fn unclosed_frontmatter_is_an_error() {
+        let parsed = parse_skill_md("---\nname: demo\n");
+        assert!(parsed.fields.is_none());
+        assert_eq!(parsed.diagnostics[0].severity, DiagnosticSeverity::Error);
+    }
+
+    #[test]
+    fn yaml_error_reports_line_in_skill_md() {
+        let parsed = parse_skill_md("---\nname: demo\ndescription: [unclosed\n---\n");
+        assert!(parsed.fields.is_none());
+        let diag = &parsed.diagnostics[0];
+        assert_eq!(diag.severity, DiagnosticSeverity::Error);
+        assert!(diag.line.is_some_and(|l| l >= 3));
+        assert!(!diag.message.contains(" at line "));
+    }
+}
@@ -2,9 +2,19 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use serde::Serialize;
-
-fn home_dir() -> Result<PathBuf, String> {
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::commands::detect::detect_platform;
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::files::is_installed_skill_dir;
+use crate::commands::frontmatter::{parse_skill_md, FrontmatterDiagnostic};
+use crate::commands::license::{detect_license, LicenseInfo};
+use crate::commands::platforms::{all_platforms, platform_spec};
+use crate::commands::record::read_record;
+use crate::commands::toggle::disabled_skill_metas;
+
+pub(crate) fn home_dir() -> CommandResult<PathBuf> {
     if let Ok(p) = std::env::var("USERPROFILE") {
         if !p.trim().is_empty() {
             return Ok(PathBuf::from(p));
@@ -15,132 +25,140 @@ fn home_dir() -> Result<PathBuf, String> {
             return Ok(PathBuf::from(p));
         }
     }
-    Err("无法定位用户目录（USERPROFILE/HOME）".into())
-}
-
-/// 各平台用于「检测 agent 是否存在」的父目录路径
-fn platform_detection_path(platform: &str) -> Result<PathBuf, String> {
-    match platform {
-        "claude" => Ok(home_dir()?.join(".claude")),
-        "antigravity" => Ok(home_dir()?.join(".gemini").join("antigravity")),
-        "gemini" => Ok(home_dir()?.join(".gemini")),
-        _ => Err("platform 仅支持 claude/antigravity/gemini".into()),
-    }
+    Err(CommandError::HomeNotFound)
 }
 
-/// 检测本机存在的 agent 平台（以目录存在为准）
+/// 检测本机已安装的 agent 平台（PATH 中存在可执行文件；未定义可执行文件的平台以配置目录为准）
 #[tauri::command]
-pub fn get_detected_platforms() -> Result<Vec<String>, String> {
-    let platforms = PLATFORMS;
-    let detected: Vec<String> = platforms
+pub fn get_detected_platforms() -> CommandResult<Vec<String>> {
+    let detected: Vec<String> = all_platforms()
         .iter()
-        .filter(|p| {
-            platform_detection_path(*p)
-                .map(|path| path.exists())
-                .unwrap_or(false)
-        })
-        .map(|p| (*p).to_string())
+        .map(|spec| detect_platform(spec, false))
+        .filter(|d| d.installed)
+        .map(|d| d.id)
         .collect();
     Ok(detected)
 }
 
 /// 获取全局 skills 目录（主路径，用于安装等）
-pub(crate) fn skills_dir_for(platform: &str) -> Result<PathBuf, String> {
-    match platform {
-        "claude" => Ok(home_dir()?.join(".claude").join("skills")),
-        "antigravity" => Ok(home_dir()?.join(".gemini").join("antigravity").join("skills")),
-        "gemini" => Ok(home_dir()?.join(".gemini").join("skills")),
-        _ => Err("platform 仅支持 claude/antigravity/gemini".into()),
-    }
+pub(crate) fn skills_dir_for(platform: &str) -> CommandResult<PathBuf> {
+    platform_spec(platform)?.global_skills_dir()
 }
 
 /// 获取项目级 skills 目录
-pub(crate) fn skills_dir_for_project(platform: &str, project_root: &Path) -> Result<PathBuf, String> {
-    let sub_dir = match platform {
-        "claude" => ".claude/skills",
-        "antigravity" => ".agent/skills",
-        "gemini" => ".gemini/skills",
-        _ => return Err("platform 仅支持 claude/antigravity/gemini".into()),
-    };
-    Ok(project_root.join(sub_dir))
+pub(crate) fn skills_dir_for_project(platform: &str, project_root: &Path) -> CommandResult<PathBuf> {
+    platform_spec(platform)?.project_skills_dir(project_root)
 }
 
-/// 校验路径是否在合法的 skills 目录下
-fn is_valid_skills_path(path: &Path) -> bool {
-    let home = home_dir().ok();
-    if let Some(home_path) = &home {
-        if path.starts_with(home_path) {
-            return true;
-        }
+/// skill 安装范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillScope {
+    Global,
+    Project,
+}
+
+/// 某个平台需要扫描的 skills 目录：全局目录，以及给定项目根目录时的项目级目录
+pub(crate) fn scoped_skill_dirs(platform: &str, project_root: Option<&Path>) -> CommandResult<Vec<(SkillScope, PathBuf)>> {
+    let spec = platform_spec(platform)?;
+    let mut dirs = vec![];
+    if let Ok(dir) = spec.global_skills_dir() {
+        dirs.push((SkillScope::Global, dir));
     }
-    PLATFORMS.iter().any(|p| {
-        if let Ok(global_dir) = skills_dir_for(p) {
-            if let Ok(global_canonical) = global_dir.canonicalize() {
-                if let Ok(path_canonical) = path.canonicalize() {
-                    return path_canonical.starts_with(&global_canonical) ||
-                           path_canonical.starts_with(&global_canonical.join(".."));
-                }
-            }
+    if let Some(root) = project_root {
+        if let Ok(dir) = spec.project_skills_dir(root) {
+            dirs.push((SkillScope::Project, dir));
         }
-        false
-    })
+    }
+    Ok(dirs)
+}
+
+fn project_root_arg(project_root: &Option<String>) -> Option<&Path> {
+    project_root.as_deref().map(str::trim).filter(|p| !p.is_empty()).map(Path::new)
 }
 
-const PLATFORMS: &[&str] = &["claude", "antigravity", "gemini"];
+/// 列出目录下的 skill 子目录（使用 path.is_dir() 以支持符号链接）
+fn list_skill_dirs(dir: &Path) -> CommandResult<Vec<(String, PathBuf)>> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let rd = fs::read_dir(dir).map_err(|e| CommandError::io("读取目录", dir, e))?;
+    let mut out: Vec<(String, PathBuf)> = rd
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| e.file_name().to_str().map(|s| (s.to_string(), e.path())))
+        .collect();
+    out.sort();
+    Ok(out)
+}
 
-/// 批量获取各 skill 在哪些平台已安装（skill_id -> [platform, ...]）
+/// 校验路径是否为已安装的 skill 目录：父目录解析符号链接后，须为平台全局目录、项目级目录或停用区
+/// 下的直接子目录（与 files::skill_root 同一规则）。只解析父目录，skill 目录本身是符号链接时校验的是链接所在位置
+pub(crate) fn is_valid_skills_path(path: &Path) -> bool {
+    let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+        return false;
+    };
+    parent.canonicalize().is_ok_and(|p| is_installed_skill_dir(&p.join(name)))
+}
+
+/// skill 所在位置
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillLocation {
+    pub platform: String,
+    pub scope: SkillScope,
+}
+
+/// 批量获取各 skill 的安装位置（skill_id -> [{platform, scope}, ...]）；给定 project_root 时同时扫描项目级目录
 #[tauri::command]
-pub fn get_installed_platforms_for_skills(ids: Vec<String>) -> Result<HashMap<String, Vec<String>>, String> {
+pub fn get_installed_platforms_for_skills(
+    ids: Vec<String>,
+    project_root: Option<String>,
+) -> CommandResult<HashMap<String, Vec<SkillLocation>>> {
+    let root = project_root_arg(&project_root);
+    let mut dirs = vec![];
+    for spec in all_platforms() {
+        for (scope, dir) in scoped_skill_dirs(&spec.id, root)? {
+            dirs.push((spec.id.clone(), scope, dir));
+        }
+    }
     let mut result = HashMap::new();
     for id in ids {
         if id.trim().is_empty() {
             continue;
         }
-        let mut platforms = Vec::new();
-        for platform in PLATFORMS {
-            if let Ok(dir) = skills_dir_for(platform) {
-                if dir.join(&id).exists() {
-                    platforms.push((*platform).to_string());
-                }
-            }
-        }
-        if !platforms.is_empty() {
-            result.insert(id, platforms);
+        let locations: Vec<SkillLocation> = dirs
+            .iter()
+            .filter(|(_, _, dir)| dir.join(&id).exists())
+            .map(|(platform, scope, _)| SkillLocation { platform: platform.clone(), scope: *scope })
+            .collect();
+        if !locations.is_empty() {
+            result.insert(id, locations);
         }
     }
     Ok(result)
 }
 
-/// 获取在任意已检测平台中已安装的 skill ID 列表（用于发现页「已安装」展示）
+/// 获取在任意平台中已安装的 skill ID 列表（用于发现页「已安装」展示）
 #[tauri::command]
-pub fn get_installed_skill_ids_anywhere() -> Result<Vec<String>, String> {
-    let platforms = get_detected_platforms()?;
-    let mut all_ids = std::collections::HashSet::new();
-    for platform in &platforms {
-        if let Ok(ids) = get_installed_skill_ids(platform.clone()) {
+pub fn get_installed_skill_ids_anywhere(project_root: Option<String>) -> CommandResult<Vec<String>> {
+    let mut all_ids = std::collections::BTreeSet::new();
+    for spec in all_platforms() {
+        if let Ok(ids) = get_installed_skill_ids(spec.id, project_root.clone()) {
             all_ids.extend(ids);
         }
     }
-    let mut ids: Vec<String> = all_ids.into_iter().collect();
-    ids.sort();
-    Ok(ids)
+    Ok(all_ids.into_iter().collect())
 }
 
-/// 获取本地已安装 skill 目录名列表（扫描各平台 skills 目录；支持符号链接）
+/// 获取本地已安装 skill 目录名列表（扫描平台全局目录及可选的项目级目录；支持符号链接）
 #[tauri::command]
-pub fn get_installed_skill_ids(platform: String) -> Result<Vec<String>, String> {
-    let dir = skills_dir_for(platform.trim())?;
-    if !dir.exists() {
-        return Ok(vec![]);
+pub fn get_installed_skill_ids(platform: String, project_root: Option<String>) -> CommandResult<Vec<String>> {
+    let mut ids = vec![];
+    for (_, dir) in scoped_skill_dirs(platform.trim(), project_root_arg(&project_root))? {
+        ids.extend(list_skill_dirs(&dir)?.into_iter().map(|(id, _)| id));
     }
-
-    let rd = fs::read_dir(&dir).map_err(|e| format!("读取目录失败 {}: {e}", dir.display()))?;
-    let mut ids: Vec<String> = rd
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_dir()) // 使用 path.is_dir() 以支持符号链接
-        .filter_map(|e| e.file_name().to_str().map(|s| s.to_string()))
-        .collect();
     ids.sort();
+    ids.dedup();
     Ok(ids)
 }
 
@@ -152,9 +170,18 @@ pub struct InstalledSkillMeta {
     pub tags: Vec<String>,
     pub install_path: String,
     pub skill_md_path: Option<String>,
+    /// 许可证：优先取安装记录，无记录时现场识别
+    pub license: LicenseInfo,
+    pub scope: SkillScope,
+    /// 是否启用；停用的 skill 位于 SkillHub 的停用区，agent 不可见
+    pub enabled: bool,
+    /// SKILL.md frontmatter 的完整内容；缺失或解析失败时为 None
+    pub frontmatter: Option<Map<String, Value>>,
+    /// frontmatter 解析问题（语法错误、未闭合等），带行号
+    pub frontmatter_diagnostics: Vec<FrontmatterDiagnostic>,
 }
 
-fn find_skill_md(dir: &Path, max_depth: usize) -> Option<PathBuf> {
+pub(crate) fn find_skill_md(dir: &Path, max_depth: usize) -> Option<PathBuf> {
     let direct = dir.join("SKILL.md");
     if direct.exists() {
         return Some(direct);
@@ -174,73 +201,8 @@ fn find_skill_md(dir: &Path, max_depth: usize) -> Option<PathBuf> {
     None
 }
 
-fn parse_frontmatter(md: &str) -> (Option<String>, Option<String>, Vec<String>) {
-    let md = md.replace("\r\n", "\n");
-    if !md.starts_with("---\n") {
-        return (None, None, vec![]);
-    }
-    let rest = &md["---\n".len()..];
-    let end = rest.find("\n---");
-    let Some(end_idx) = end else {
-        return (None, None, vec![]);
-    };
-    let fm = &rest[..end_idx];
-
-    let mut name: Option<String> = None;
-    let mut desc: Option<String> = None;
-    let mut tags: Vec<String> = vec![];
-
-    for line in fm.lines() {
-        let line = line.trim();
-        if line.is_empty() || line.starts_with('#') {
-            continue;
-        }
-        if let Some(v) = line.strip_prefix("name:") {
-            let v = v.trim().trim_matches('"').trim_matches('\'');
-            if !v.is_empty() {
-                name = Some(v.to_string());
-            }
-            continue;
-        }
-        if let Some(v) = line.strip_prefix("description:") {
-            let v = v.trim().trim_matches('"').trim_matches('\'');
-            if !v.is_empty() {
-                desc = Some(v.to_string());
-            }
-            continue;
-        }
-        if let Some(v) = line.strip_prefix("tags:") {
-            let raw = v.trim();
-            // 支持 tags: [a, b] / tags: a,b / tags: a
-            let cleaned = raw.trim_matches('[').trim_matches(']');
-            let parts: Vec<&str> = cleaned
-                .split(',')
-                .map(|s| s.trim().trim_matches('"').trim_matches('\''))
-                .filter(|s| !s.is_empty())
-                .collect();
-            if !parts.is_empty() {
-                tags = parts.into_iter().map(|s| s.to_string()).collect();
-            }
-        }
-    }
-
-    (name, desc, tags)
-}
-
-fn parse_fallback(md: &str) -> (Option<String>, Option<String>) {
-    let md = md.replace("\r\n", "\n");
-    // 去掉 frontmatter（若存在）
-    let body = if md.starts_with("---\n") {
-        if let Some(end_idx) = md["---\n".len()..].find("\n---") {
-            let after = &md["---\n".len() + end_idx + "\n---".len()..];
-            after
-        } else {
-            md.as_str()
-        }
-    } else {
-        md.as_str()
-    };
-
+/// 无 frontmatter 字段时的兜底：正文第一个 `# 标题` 及其后首段
+fn parse_fallback(body: &str) -> (Option<String>, Option<String>) {
     let mut name: Option<String> = None;
     let mut desc: Option<String> = None;
 
@@ -259,87 +221,95 @@ fn parse_fallback(md: &str) -> (Option<String>, Option<String>) {
     (name, desc)
 }
 
-/// 获取本地已安装技能元数据（扫描目录 + 解析 SKILL.md；支持符号链接）
-#[tauri::command]
-pub fn get_installed_skills(platform: String) -> Result<Vec<InstalledSkillMeta>, String> {
-    let dir = skills_dir_for(platform.trim())?;
-    if !dir.exists() {
-        return Ok(vec![]);
-    }
-
-    let rd = fs::read_dir(&dir).map_err(|e| format!("读取目录失败 {}: {e}", dir.display()))?;
-    let mut out: Vec<InstalledSkillMeta> = vec![];
-
-    for ent in rd.flatten() {
-        let install_path = ent.path();
-        if !install_path.is_dir() {
-            continue; // 使用 path.is_dir() 以支持符号链接
-        }
-        let id = ent.file_name().to_string_lossy().to_string();
-        let skill_md = find_skill_md(&install_path, 3);
-        let mut name: Option<String> = None;
-        let mut description: Option<String> = None;
-        let mut tags: Vec<String> = vec![];
-
-        if let Some(md_path) = &skill_md {
-            if let Ok(content) = fs::read_to_string(md_path) {
-                let (n1, d1, t1) = parse_frontmatter(&content);
-                name = n1;
-                description = d1;
-                tags = t1;
-                if name.is_none() || description.is_none() {
-                    let (n2, d2) = parse_fallback(&content);
-                    if name.is_none() {
-                        name = n2;
-                    }
-                    if description.is_none() {
-                        description = d2;
-                    }
+/// 读取单个 skill 目录的元数据（解析 SKILL.md）
+pub(crate) fn read_skill_meta(id: String, install_path: &Path, scope: SkillScope) -> InstalledSkillMeta {
+    let skill_md = find_skill_md(install_path, 3);
+    let mut name: Option<String> = None;
+    let mut description: Option<String> = None;
+    let mut tags: Vec<String> = vec![];
+    let mut frontmatter: Option<Map<String, Value>> = None;
+    let mut frontmatter_diagnostics: Vec<FrontmatterDiagnostic> = vec![];
+
+    if let Some(md_path) = &skill_md {
+        if let Ok(content) = fs::read_to_string(md_path) {
+            let parsed = parse_skill_md(&content);
+            name = parsed.str_field("name");
+            description = parsed.str_field("description");
+            tags = parsed.list_field("tags");
+            if name.is_none() || description.is_none() {
+                let (n2, d2) = parse_fallback(&parsed.body);
+                if name.is_none() {
+                    name = n2;
+                }
+                if description.is_none() {
+                    description = d2;
                 }
             }
+            frontmatter = parsed.fields;
+            frontmatter_diagnostics = parsed.diagnostics;
         }
+    }
 
-        out.push(InstalledSkillMeta {
-            id,
-            name,
-            description,
-            tags,
-            install_path: install_path.to_string_lossy().to_string(),
-            skill_md_path: skill_md.map(|p| p.to_string_lossy().to_string()),
-        });
+    let license = read_record(install_path)
+        .and_then(|r| r.license)
+        .unwrap_or_else(|| detect_license(install_path, None));
+
+    InstalledSkillMeta {
+        id,
+        name,
+        description,
+        tags,
+        install_path: install_path.to_string_lossy().to_string(),
+        skill_md_path: skill_md.map(|p| p.to_string_lossy().to_string()),
+        license,
+        scope,
+        enabled: true,
+        frontmatter,
+        frontmatter_diagnostics,
     }
+}
 
-    out.sort_by(|a, b| a.id.cmp(&b.id));
+/// 获取本地已安装技能元数据（扫描全局目录及可选的项目级目录 + 解析 SKILL.md；支持符号链接；含已停用的 skill）
+#[tauri::command]
+pub fn get_installed_skills(platform: String, project_root: Option<String>) -> CommandResult<Vec<InstalledSkillMeta>> {
+    let mut out: Vec<InstalledSkillMeta> = vec![];
+    let root = project_root_arg(&project_root);
+    for (scope, dir) in scoped_skill_dirs(platform.trim(), root)? {
+        for (id, install_path) in list_skill_dirs(&dir)? {
+            out.push(read_skill_meta(id, &install_path, scope));
+        }
+    }
+    out.extend(disabled_skill_metas(platform.trim(), root));
+    out.sort_by(|a, b| a.id.cmp(&b.id).then(a.scope.cmp(&b.scope)));
     Ok(out)
 }
 
 /// 卸载 skill：删除指定路径的 skill 目录
 #[tauri::command]
-pub fn uninstall_skill(skill_id: String, install_path: String) -> Result<(), String> {
+pub fn uninstall_skill(skill_id: String, install_path: String) -> CommandResult<()> {
     if skill_id.trim().is_empty() {
-        return Err("skill_id 不能为空".into());
+        return Err(CommandError::invalid("skill_id", "不能为空"));
     }
     if install_path.trim().is_empty() {
-        return Err("install_path 不能为空".into());
+        return Err(CommandError::invalid("install_path", "不能为空"));
     }
 
     let path = PathBuf::from(&install_path);
     if !path.exists() {
-        return Err("安装路径不存在，可能已被删除".into());
+        return Err(CommandError::not_found(&path));
     }
 
     // 校验路径安全
     if !is_valid_skills_path(&path) {
-        return Err("路径不在合法的 skills 目录下".into());
+        return Err(CommandError::PathNotAllowed { path: install_path });
     }
 
     // 防止误删：路径必须以 skill_id 结尾
     if path.file_name().map(|n| n.to_string_lossy() != skill_id).unwrap_or(true) {
-        return Err("路径与 skill_id 不匹配".into());
+        return Err(CommandError::invalid("install_path", "与 skill_id 不匹配"));
     }
 
-    fs::remove_dir_all(&path)
-        .map_err(|e| format!("删除目录失败: {}", e))?;
+    fs::remove_dir_all(&path).map_err(|e| CommandError::io("删除目录", &path, e))?;
 
     Ok(())
 }
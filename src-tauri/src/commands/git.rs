@@ -2,8 +2,16 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use crate::commands::InstallSkillPayload;
+use crate::commands::{InstallOutcome, InstallSkillPayload};
+use crate::commands::error::{CommandError, CommandResult};
 use crate::commands::fs::{get_detected_platforms, skills_dir_for, skills_dir_for_project};
+use crate::commands::license::{apply_policy, detect_license, LicenseInfo, LicensePolicy};
+use crate::commands::limits::{check_staged_tree, InstallLimits};
+use crate::commands::platforms::platform_spec;
+use crate::commands::record::{now_secs, write_record, InstallRecord};
+use crate::commands::retry::{with_retry, AttemptRecord};
+use crate::commands::scan::{enforce_policy, scan_dir, ScanPolicy, ScanReport};
+use crate::commands::settings::load_settings;
 
 /// 将 repo 标识转换为可用的 git URL。
 ///
@@ -11,15 +19,15 @@ use crate::commands::fs::{get_detected_platforms, skills_dir_for, skills_dir_for
 /// - `owner/repo`
 /// - `https://github.com/owner/repo`
 /// - `https://github.com/owner/repo.git`
-fn normalize_repo_url(repo: &str) -> Result<String, String> {
+pub(crate) fn normalize_repo_url(repo: &str) -> CommandResult<String> {
     let repo = repo.trim();
     if repo.is_empty() {
-        return Err("repo 不能为空".into());
+        return Err(CommandError::invalid("repo", "不能为空"));
     }
 
     // 简单防注入：禁止空格/控制字符/引号
     if repo.chars().any(|c| c.is_whitespace() || c == '"' || c == '\'') {
-        return Err("repo 含非法字符".into());
+        return Err(CommandError::invalid("repo", "含非法字符"));
     }
 
     if repo.starts_with("http://") || repo.starts_with("https://") {
@@ -29,13 +37,16 @@ fn normalize_repo_url(repo: &str) -> Result<String, String> {
     // owner/repo
     let parts: Vec<&str> = repo.split('/').collect();
     if parts.len() != 2 || parts[0].is_empty() || parts[1].is_empty() {
-        return Err("repo 需为 owner/repo 或 https://github.com/owner/repo(.git)".into());
+        return Err(CommandError::invalid("repo", "需为 owner/repo 或 https://github.com/owner/repo(.git)"));
     }
 
     Ok(format!("https://github.com/{}.git", repo))
 }
 
-fn unique_temp_dir(prefix: &str) -> PathBuf {
+/// 安装时临时 clone 目录的前缀
+pub(crate) const CLONE_TEMP_PREFIX: &str = "skillhub_clone";
+
+pub(crate) fn unique_temp_dir(prefix: &str) -> PathBuf {
     let mut p = std::env::temp_dir();
     let pid = std::process::id();
     let ts = std::time::SystemTime::now()
@@ -46,58 +57,141 @@ fn unique_temp_dir(prefix: &str) -> PathBuf {
     p
 }
 
-fn run_git(args: &[&str], cwd: Option<&Path>) -> Result<(), String> {
+fn run_git(args: &[&str], cwd: Option<&Path>) -> CommandResult<()> {
+    git_output(args, cwd).map(|_| ())
+}
+
+/// 执行 git 并返回 stdout
+pub(crate) fn git_output(args: &[&str], cwd: Option<&Path>) -> CommandResult<String> {
     let mut cmd = Command::new("git");
     cmd.args(args);
+    load_settings().network.apply_to_command(&mut cmd);
     if let Some(dir) = cwd {
         cmd.current_dir(dir);
     }
-    let out = cmd.output().map_err(|e| format!("git 执行失败: {e}"))?;
+    let command = format!("git {}", args.join(" "));
+    let out = cmd.output().map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => CommandError::GitMissing,
+        _ => CommandError::command_failed(command.clone(), None, "", &e.to_string()),
+    })?;
     if out.status.success() {
-        return Ok(());
+        return Ok(String::from_utf8_lossy(&out.stdout).trim().to_string());
     }
-    let stdout = String::from_utf8_lossy(&out.stdout);
-    let stderr = String::from_utf8_lossy(&out.stderr);
-    Err(format!(
-        "git 命令失败: git {}\nstdout:\n{}\nstderr:\n{}",
-        args.join(" "),
-        stdout,
-        stderr
+    Err(CommandError::command_failed(
+        command,
+        out.status.code(),
+        &String::from_utf8_lossy(&out.stdout),
+        &String::from_utf8_lossy(&out.stderr),
     ))
 }
 
-fn ensure_dir(path: &Path) -> Result<(), String> {
-    fs::create_dir_all(path).map_err(|e| format!("创建目录失败 {}: {e}", path.display()))
+pub(crate) fn ensure_dir(path: &Path) -> CommandResult<()> {
+    fs::create_dir_all(path).map_err(|e| CommandError::io("创建目录", path, e))
 }
 
-fn remove_dir_if_exists(path: &Path) {
+pub(crate) fn remove_dir_if_exists(path: &Path) {
     let _ = fs::remove_dir_all(path);
 }
 
-/// 校验 skill_id 安全（防注入）
-fn validate_skill_id(id: &str) -> Result<(), String> {
-    let id = id.trim();
-    if id.is_empty() {
-        return Err("skill id 不能为空".into());
+/// 按原样重建符号链接（不读取链接指向的内容）
+#[cfg(unix)]
+fn copy_symlink(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(from)?, to)
+}
+
+#[cfg(windows)]
+fn copy_symlink(from: &Path, to: &Path) -> std::io::Result<()> {
+    let target = fs::read_link(from)?;
+    if from.is_dir() {
+        std::os::windows::fs::symlink_dir(target, to)
+    } else {
+        std::os::windows::fs::symlink_file(target, to)
     }
-    if id.chars().any(|c| c.is_whitespace() || c == '/' || c == '\\' || c == '"' || c == '\'') {
-        return Err("skill id 含非法字符".into());
+}
+
+/// 递归复制目录；符号链接按链接本身重建，不跟随（避免指向 `.` 的链接无限递归，或把链接指向的目录外内容复制进来）
+pub(crate) fn copy_dir_all(src: &Path, dst: &Path) -> CommandResult<()> {
+    ensure_dir(dst)?;
+    let rd = fs::read_dir(src).map_err(|e| CommandError::io("读取目录", src, e))?;
+    for ent in rd.flatten() {
+        let from = ent.path();
+        let to = dst.join(ent.file_name());
+        let meta = fs::symlink_metadata(&from).map_err(|e| CommandError::io("读取文件信息", &from, e))?;
+        if meta.file_type().is_symlink() {
+            copy_symlink(&from, &to).map_err(|e| CommandError::io("复制链接", &from, e))?;
+        } else if meta.is_dir() {
+            copy_dir_all(&from, &to)?;
+        } else if meta.is_file() {
+            fs::copy(&from, &to).map_err(|e| CommandError::io("复制文件", &from, e))?;
+        }
     }
     Ok(())
 }
 
-/// 将 SkillHub 平台映射为 skills CLI 的 --agent 参数
-fn platform_to_agent(platform: &str) -> &'static str {
-    match platform.trim().to_lowercase().as_str() {
-        "claude" => "claude-code",
-        "antigravity" => "antigravity",
-        "gemini" => "gemini-cli",
-        _ => "claude-code",
+/// 移动目录：优先 rename，跨分区（如系统临时目录与用户目录不在同一磁盘）时回退为复制 + 删除
+pub(crate) fn move_dir(src: &Path, dst: &Path) -> CommandResult<()> {
+    if fs::rename(src, dst).is_ok() {
+        return Ok(());
     }
+    copy_dir_all(src, dst).inspect_err(|_| remove_dir_if_exists(dst))?;
+    remove_dir_if_exists(src);
+    Ok(())
+}
+
+/// 激活前对暂存内容执行的检查（限额、安全扫描、许可证）
+struct StageChecks {
+    limits: InstallLimits,
+    scan_policy: ScanPolicy,
+    license_policy: Option<LicensePolicy>,
+}
+
+impl StageChecks {
+    fn from_payload(payload: &InstallSkillPayload) -> Self {
+        Self {
+            limits: payload.limits.clone().unwrap_or_default(),
+            scan_policy: payload.scan_policy.clone().unwrap_or_default(),
+            license_policy: payload.license_policy.clone(),
+        }
+    }
+
+    /// repo_root 为暂存的仓库根目录（用于查找根目录 LICENSE）；取不到时为失败原因，
+    /// skill 目录内也找不到许可证则记为 unknown 并附上原因
+    fn run(&self, dir: &Path, repo_root: Result<&Path, String>) -> CommandResult<(ScanReport, LicenseInfo)> {
+        check_staged_tree(dir, &self.limits)?;
+        let report = scan_dir(dir);
+        enforce_policy(&report, &self.scan_policy)?;
+        let mut license = match repo_root {
+            Ok(root) => detect_license(dir, Some(root)),
+            Err(reason) => {
+                let mut info = detect_license(dir, None);
+                if info.spdx.is_none() && info.source.is_none() {
+                    info.source = Some("unknown".into());
+                    info.unresolved_reason = Some(reason);
+                }
+                info
+            }
+        };
+        if let Some(policy) = &self.license_policy {
+            apply_policy(&mut license, policy)?;
+        }
+        Ok((report, license))
+    }
+}
+
+/// 校验 skill_id 安全（防注入）
+pub(crate) fn validate_skill_id(id: &str) -> CommandResult<()> {
+    let id = id.trim();
+    if id.is_empty() {
+        return Err(CommandError::invalid("skill id", "不能为空"));
+    }
+    if id == "." || id == ".." || id.chars().any(|c| c.is_whitespace() || c == '/' || c == '\\' || c == '"' || c == '\'') {
+        return Err(CommandError::invalid("skill id", "含非法字符"));
+    }
+    Ok(())
 }
 
 /// 通过 npx skills add 安装（优先方案，适配 skills.sh 官方 CLI）
-/// - agent: skills CLI 的 --agent（如 claude-code, cursor, antigravity, gemini-cli）
+/// - agent: skills CLI 的 --agent（如 claude-code, cursor, codex, github-copilot）
 /// - is_global: true 用 -g 安装到用户目录，false 安装到项目
 /// - cwd: 项目安装时的当前工作目录
 fn run_npx_skills_add(
@@ -106,7 +200,7 @@ fn run_npx_skills_add(
     agent: &str,
     is_global: bool,
     cwd: Option<&Path>,
-) -> Result<(), String> {
+) -> CommandResult<()> {
     let repo_url = if repo.starts_with("http://") || repo.starts_with("https://") {
         repo.to_string()
     } else {
@@ -128,60 +222,136 @@ fn run_npx_skills_add(
         npx_args.push("-g");
     }
 
-    // Windows 下 npx 是 npx.cmd 批处理，Rust Command 无法直接执行，需通过 cmd /c 调用
-    let mut cmd = if cfg!(target_os = "windows") {
-        let mut c = Command::new("cmd");
-        c.args(["/c", "npx"]);
-        c.args(&npx_args);
-        c
-    } else {
-        let mut c = Command::new("npx");
-        c.args(&npx_args);
-        c
-    };
+    let mut cmd = npx_command(&npx_args);
     cmd.env("DISABLE_TELEMETRY", "1");
+    load_settings().network.apply_to_command(&mut cmd);
     if let Some(dir) = cwd {
         cmd.current_dir(dir);
     }
 
-    let out = cmd.output().map_err(|e| format!("执行 npx 失败（请确保已安装 Node.js）: {e}"))?;
+    let out = cmd.output().map_err(|_| CommandError::NpxMissing)?;
 
     if out.status.success() {
         return Ok(());
     }
-    let stderr = String::from_utf8_lossy(&out.stderr);
-    let stdout = String::from_utf8_lossy(&out.stdout);
-    Err(format!(
-        "npx skills add 失败:\n{}{}",
-        if !stdout.is_empty() {
-            format!("stdout:\n{stdout}\n")
-        } else {
-            String::new()
-        },
-        if !stderr.is_empty() {
-            format!("stderr:\n{stderr}")
-        } else {
-            "无详细信息".to_string()
-        }
+    Err(CommandError::command_failed(
+        format!("npx {}", npx_args.join(" ")),
+        out.status.code(),
+        &String::from_utf8_lossy(&out.stdout),
+        &String::from_utf8_lossy(&out.stderr),
     ))
 }
 
-/// 检查 npx 是否可用（Windows 下通过 cmd /c 调用）
-fn npx_available() -> bool {
-    let result = if cfg!(target_os = "windows") {
-        Command::new("cmd")
-            .args(["/c", "npx", "--version"])
-            .output()
+/// 构造 npx 命令：Windows 下 npx 是 npx.cmd 批处理，Rust Command 无法直接执行，需通过 cmd /c 调用
+pub(crate) fn npx_command(args: &[&str]) -> Command {
+    if cfg!(target_os = "windows") {
+        let mut c = Command::new("cmd");
+        c.args(["/c", "npx"]);
+        c.args(args);
+        c
     } else {
-        Command::new("npx")
-            .arg("--version")
-            .output()
+        let mut c = Command::new("npx");
+        c.args(args);
+        c
+    }
+}
+
+/// 检查 npx 是否可用
+fn npx_available() -> bool {
+    npx_command(&["--version"]).output().map(|o| o.status.success()).unwrap_or(false)
+}
+
+/// 将仓库检出到 tmp，返回 skill 所在目录：
+/// - 有 sub_path 时 sparse checkout 该子目录（找不到则回退 skills/<sub_path>）
+/// - 否则完整 clone，返回 tmp 本身
+/// - rev 指定时检出该 commit，否则为默认分支 HEAD
+pub(crate) fn stage_repo(
+    url: &str,
+    sub_path: Option<&str>,
+    rev: Option<&str>,
+    tmp: &Path,
+    attempts: &mut Vec<AttemptRecord>,
+) -> CommandResult<PathBuf> {
+    let tmp_path = tmp.to_string_lossy().to_string();
+    let mut checkout_args = vec!["checkout"];
+    if let Some(rev) = rev {
+        checkout_args.push(rev);
+    }
+
+    let Some(sub_path) = sub_path else {
+        // 完整 clone 只取目标版本的浅克隆，不下载历史；clone / fetch 涉及网络，可重试
+        match rev {
+            None => with_retry("git clone", attempts, || remove_dir_if_exists(tmp), || {
+                run_git(&["clone", "--depth", "1", url, &tmp_path], None)
+            })?,
+            Some(rev) => {
+                with_retry("git clone", attempts, || remove_dir_if_exists(tmp), || {
+                    run_git(&["clone", "--depth", "1", "--no-checkout", url, &tmp_path], None)
+                })?;
+                with_retry("git fetch", attempts, || {}, || run_git(&["fetch", "--depth", "1", "origin", rev], Some(tmp)))?;
+                run_git(&checkout_args, Some(tmp))?;
+            }
+        }
+        return Ok(tmp.to_path_buf());
     };
-    result.map(|o| o.status.success()).unwrap_or(false)
+
+    // sparse checkout 到 tmp；clone 与 checkout（按需拉取 blob）涉及网络，可重试
+    with_retry("git clone", attempts, || remove_dir_if_exists(tmp), || {
+        run_git(&["clone", "--filter=blob:none", "--no-checkout", url, &tmp_path], None)
+    })?;
+    run_git(&["sparse-checkout", "init", "--cone"], Some(tmp))?;
+    run_git(&["sparse-checkout", "set", sub_path], Some(tmp))?;
+    with_retry("git checkout", attempts, || {}, || run_git(&checkout_args, Some(tmp)))?;
+
+    // git sparse-checkout set 即使路径不存在也可能不报错，因此这里做二次校验/回退
+    let mut src = tmp.join(sub_path);
+    if !src.exists() {
+        // 常见情况：真实目录在 skills/<sub_path>
+        let trimmed = sub_path.trim().trim_start_matches("./").trim_start_matches("skills/").to_string();
+        let alt = format!("skills/{}", trimmed);
+        run_git(&["sparse-checkout", "set", &alt], Some(tmp))?;
+        with_retry("git checkout", attempts, || {}, || run_git(&checkout_args, Some(tmp)))?;
+        src = tmp.join(&alt);
+    }
+
+    if !src.exists() {
+        // 输出更友好的提示：列出 skills 下可用目录（如果存在）
+        let skills_dir = tmp.join("skills");
+        let mut hint = String::new();
+        if skills_dir.exists() {
+            if let Ok(read) = fs::read_dir(&skills_dir) {
+                let mut names: Vec<String> = read
+                    .filter_map(|e| e.ok())
+                    .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+                    .filter_map(|e| e.file_name().to_str().map(|s| s.to_string()))
+                    .collect();
+                names.sort();
+                if !names.is_empty() {
+                    hint = format!("可用 skills 子目录示例: {}", names.into_iter().take(12).collect::<Vec<_>>().join(", "));
+                }
+            }
+        }
+        return Err(CommandError::invalid(
+            "sub_path",
+            format!("'{}' 在仓库中不存在（{}）。{}", sub_path, src.display(), hint),
+        ));
+    }
+    Ok(src)
 }
 
-/// 实际安装逻辑（供 commands/mod.rs 的 tauri::command 包装调用）
-pub async fn install_skill_impl(payload: InstallSkillPayload) -> Result<String, String> {
+/// 只检出仓库根目录下的文件（浅克隆 + cone 模式 sparse checkout，不含任何子目录），
+/// 供 npx 安装查找 repo 根目录的 LICENSE
+fn stage_repo_root_files(url: &str, tmp: &Path, attempts: &mut Vec<AttemptRecord>) -> CommandResult<()> {
+    let tmp_path = tmp.to_string_lossy().to_string();
+    with_retry("git clone", attempts, || remove_dir_if_exists(tmp), || {
+        run_git(&["clone", "--depth", "1", "--filter=blob:none", "--no-checkout", url, &tmp_path], None)
+    })?;
+    run_git(&["sparse-checkout", "init", "--cone"], Some(tmp))?;
+    with_retry("git checkout", attempts, || {}, || run_git(&["checkout"], Some(tmp)))
+}
+
+/// 实际安装逻辑（同步执行，供 commands/mod.rs 的 tauri::command 包装放入阻塞线程池调用）
+pub fn install_skill_impl(payload: InstallSkillPayload) -> CommandResult<InstallOutcome> {
     validate_skill_id(&payload.id)?;
     let url = normalize_repo_url(&payload.repo)?;
 
@@ -194,33 +364,76 @@ pub async fn install_skill_impl(payload: InstallSkillPayload) -> Result<String,
         skills_dir_for(platform)?.join(&payload.id)
     };
 
-    ensure_dir(&target_dir.parent().unwrap_or(&PathBuf::new()))?;
+    ensure_dir(target_dir.parent().unwrap_or(&PathBuf::new()))?;
 
     if target_dir.exists() {
-        return Err(format!("已存在同名目录，疑似已安装: {}", target_dir.display()));
+        return Err(CommandError::AlreadyInstalled { path: target_dir.display().to_string() });
     }
 
     let skill_id = payload.sub_path.as_deref().unwrap_or(&payload.id).trim();
     if skill_id.is_empty() {
-        return Err("无法确定 skill 名称".into());
+        return Err(CommandError::invalid("skill id", "无法确定 skill 名称"));
     }
     validate_skill_id(skill_id)?;
 
+    let checks = StageChecks::from_payload(&payload);
+
     let is_global = payload.project_root.is_none();
     let platform = payload.target_platform.as_deref().unwrap_or("claude");
-    let agent = platform_to_agent(platform);
+    // skills CLI 的 --agent 来自平台定义；未映射的平台只走 git
+    let spec = platform_spec(platform)?;
+    let agent = spec.npx_agent();
+    let record = |method: &str, commit: Option<String>, license: &LicenseInfo| InstallRecord {
+        id: payload.id.clone(),
+        repo: payload.repo.clone(),
+        sub_path: payload.sub_path.clone(),
+        platform: platform.to_string(),
+        project_root: payload.project_root.clone(),
+        method: method.to_string(),
+        commit,
+        installed_at: now_secs(),
+        license: Some(license.clone()),
+        local_modified_at: None,
+    };
     let cwd = payload
         .project_root
         .as_ref()
         .filter(|p| !p.trim().is_empty())
-        .map(|p| Path::new(p));
+        .map(Path::new);
+
+    let mut attempts: Vec<AttemptRecord> = vec![];
 
     // 优先使用 npx skills add；成功后校验 target_dir 是否存在，不存在则 git 回退
-    if npx_available() {
-        match run_npx_skills_add(&payload.repo, skill_id, agent, is_global, cwd.as_deref()) {
+    if let Some(agent) = agent.filter(|_| npx_available()) {
+        let npx_result = with_retry(
+            "npx skills add",
+            &mut attempts,
+            || remove_dir_if_exists(&target_dir),
+            || run_npx_skills_add(&payload.repo, skill_id, agent, is_global, cwd),
+        );
+        match npx_result {
             Ok(()) => {
                 if target_dir.exists() {
-                    return Ok(format!("安装完成: {}", target_dir.display()));
+                    // npx 直接写入目标目录，无法预先暂存：事后检查，不通过则回滚。
+                    // npx 不保留仓库其余部分，另行拉取根目录文件以识别 repo 根目录的 LICENSE
+                    let root_tmp = unique_temp_dir(CLONE_TEMP_PREFIX);
+                    remove_dir_if_exists(&root_tmp);
+                    let repo_root = stage_repo_root_files(&url, &root_tmp, &mut attempts)
+                        .map(|_| root_tmp.as_path())
+                        .map_err(|e| format!("拉取仓库根目录失败：{e}"));
+                    let checked = checks.run(&target_dir, repo_root);
+                    remove_dir_if_exists(&root_tmp);
+                    let (scan, license) = checked.inspect_err(|_| {
+                        remove_dir_if_exists(&target_dir);
+                    })?;
+                    write_record(&target_dir, &record("npx", None, &license))?;
+                    return Ok(InstallOutcome {
+                        message: format!("安装完成: {}", target_dir.display()),
+                        install_path: target_dir.to_string_lossy().to_string(),
+                        scan,
+                        license,
+                        attempts,
+                    });
                 }
                 // npx 返回成功但目标路径不存在（如 gemini-cli 装到 .agent/skills），回退 git
             }
@@ -229,93 +442,53 @@ pub async fn install_skill_impl(payload: InstallSkillPayload) -> Result<String,
     }
 
     // npx 不可用或 npx 成功但未安装到预期路径时，回退到 git sparse checkout
-    let tmp = unique_temp_dir("skillhub_clone");
+    let tmp = unique_temp_dir(CLONE_TEMP_PREFIX);
     remove_dir_if_exists(&tmp);
-    let tmp_path = tmp.to_string_lossy().to_string();
-    let target_path = target_dir.to_string_lossy().to_string();
 
     // 避免残留：任何失败都清理 tmp
     let result = (|| {
-        if let Some(sub_path) = payload.sub_path.as_deref().filter(|s| !s.trim().is_empty()) {
-            // sparse checkout 到 tmp
-            run_git(
-                &["clone", "--filter=blob:none", "--no-checkout", &url, &tmp_path],
-                None,
-            )?;
-            run_git(&["sparse-checkout", "init", "--cone"], Some(&tmp))?;
-            run_git(&["sparse-checkout", "set", sub_path], Some(&tmp))?;
-            run_git(&["checkout"], Some(&tmp))?;
-
-            // git sparse-checkout set 即使路径不存在也可能不报错，因此这里做二次校验/回退
-            let mut src_rel = sub_path.to_string();
-            let mut src = tmp.join(&src_rel);
-            if !src.exists() {
-                // 常见情况：真实目录在 skills/<sub_path>
-                let trimmed = src_rel.trim().trim_start_matches("./").trim_start_matches("skills/").to_string();
-                let alt = format!("skills/{}", trimmed);
-                run_git(&["sparse-checkout", "set", &alt], Some(&tmp))?;
-                run_git(&["checkout"], Some(&tmp))?;
-                src_rel = alt;
-                src = tmp.join(&src_rel);
-            }
-
-            if !src.exists() {
-                // 输出更友好的提示：列出 skills 下可用目录（如果存在）
-                let skills_dir = tmp.join("skills");
-                let mut hint = String::new();
-                if skills_dir.exists() {
-                    if let Ok(read) = fs::read_dir(&skills_dir) {
-                        let mut names: Vec<String> = read
-                            .filter_map(|e| e.ok())
-                            .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
-                            .filter_map(|e| e.file_name().to_str().map(|s| s.to_string()))
-                            .collect();
-                        names.sort();
-                        if !names.is_empty() {
-                            hint = format!("可用 skills 子目录示例: {}", names.into_iter().take(12).collect::<Vec<_>>().join(", "));
-                        }
-                    }
-                }
-                return Err(format!(
-                    "稀疏检出目录不存在: {}（请求 subPath='{}'）。{}",
-                    src.display(),
-                    sub_path,
-                    hint
-                ));
-            }
-            // move 子目录到 skills/{id}
-            fs::rename(&src, &target_dir).map_err(|e| {
-                format!(
-                    "移动安装目录失败: {} -> {}: {e}",
-                    src.display(),
-                    target_dir.display()
-                )
-            })?;
-        } else {
-            // 完整 clone 到目标目录
-            run_git(&["clone", &url, &target_path], None)?;
-        }
-
-        Ok::<(), String>(())
+        let sub_path = payload.sub_path.as_deref().filter(|s| !s.trim().is_empty());
+        let src = stage_repo(&url, sub_path, None, &tmp, &mut attempts)?;
+        let (scan, license) = checks.run(&src, Ok(&tmp))?;
+        let commit = git_output(&["rev-parse", "HEAD"], Some(&tmp)).ok();
+        let resolved = src.strip_prefix(&tmp).ok().map(|p| p.to_string_lossy().replace('\\', "/"));
+        // 完整 clone 时 src 即仓库根目录：.git 不计入限额，也不随 skill 安装
+        remove_dir_if_exists(&src.join(".git"));
+        // move 子目录（或完整 clone）到 skills/{id}
+        move_dir(&src, &target_dir)?;
+        Ok((scan, license, commit, resolved.filter(|p| !p.is_empty())))
     })();
 
     // 清理 tmp（无论成功失败）
     remove_dir_if_exists(&tmp);
 
-    result?;
-
-    Ok(format!("安装完成: {}", target_dir.display()))
+    let (scan, license, commit, resolved) = result?;
+    let mut rec = record("git", commit, &license);
+    // 记录实际检出的子目录（可能回退到 skills/<sub_path>），供修复时精确还原
+    rec.sub_path = resolved;
+    write_record(&target_dir, &rec)?;
+
+    Ok(InstallOutcome {
+        message: format!("安装完成: {}", target_dir.display()),
+        install_path: target_dir.to_string_lossy().to_string(),
+        scan,
+        license,
+        attempts,
+    })
 }
 
 /// 一键安装到所有已检测到的平台（仅全局，跳过已安装）
-pub async fn install_skill_to_all_platforms_impl(
+pub fn install_skill_to_all_platforms_impl(
     payload: InstallSkillPayload,
-) -> Result<crate::commands::InstallAllResult, String> {
+) -> CommandResult<crate::commands::InstallAllResult> {
     validate_skill_id(&payload.id)?;
 
     let platforms = get_detected_platforms()?;
     if platforms.is_empty() {
-        return Err("未检测到任何 agent 平台（请确保已安装 Claude Code、Antigravity 或 Gemini CLI）".into());
+        return Err(CommandError::invalid(
+            "platform",
+            "未检测到任何 agent 平台（请确保已安装受支持的 agent，或在 ~/.skillhub/platforms.json 中添加自定义平台）",
+        ));
     }
 
     let mut installed = Vec::new();
@@ -335,8 +508,11 @@ pub async fn install_skill_to_all_platforms_impl(
             sub_path: payload.sub_path.clone(),
             target_platform: Some(platform.clone()),
             project_root: None,
+            limits: payload.limits.clone(),
+            scan_policy: payload.scan_policy.clone(),
+            license_policy: payload.license_policy.clone(),
         };
-        match install_skill_impl(p).await {
+        match install_skill_impl(p) {
             Ok(_) => installed.push(platform.clone()),
             Err(e) => return Err(e),
         }
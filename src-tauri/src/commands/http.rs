@@ -0,0 +1,55 @@
+use std::fs;
+use std::time::Duration;
+
+use tauri_plugin_http::reqwest;
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::settings::{load_settings, NetworkSettings};
+
+/// 允许经后端请求的地址前缀（改写前），与 capabilities 中 http 插件的 scope 一致
+const ALLOWED_PREFIXES: &[&str] = &["https://skills.sh/"];
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn http_error(url: &str, status: Option<u16>, reason: impl ToString) -> CommandError {
+    CommandError::HttpFailed { url: url.to_string(), status, reason: reason.to_string() }
+}
+
+/// 按当前网络设置构造客户端：代理与 CA 每次从设置读取，修改后无需重启
+fn build_client(network: &NetworkSettings, url: &str) -> CommandResult<reqwest::Client> {
+    let mut builder = reqwest::Client::builder().timeout(REQUEST_TIMEOUT);
+    if let Some(proxy) = network.proxy_for(url) {
+        let mut proxy = reqwest::Proxy::all(proxy).map_err(|e| CommandError::invalid("proxy", e.to_string()))?;
+        if let Some(no_proxy) = reqwest::NoProxy::from_string(&network.no_proxy.join(",")) {
+            proxy = proxy.no_proxy(Some(no_proxy));
+        }
+        builder = builder.proxy(proxy);
+    }
+    if let Some(ca) = network.ca_bundle_path() {
+        let pem = fs::read(&ca).map_err(|e| CommandError::io("读取 CA 证书", &ca, e))?;
+        let certs = reqwest::Certificate::from_pem_bundle(&pem)
+            .map_err(|e| CommandError::invalid("ca_bundle", format!("无法解析 PEM 证书: {e}")))?;
+        for cert in certs {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+    builder.build().map_err(|e| http_error(url, None, e))
+}
+
+/// GET 文本内容（skills.sh 页面）：与 git / npx 一致地应用网络设置中的 URL 改写、代理与 CA。
+/// 只接受 skills.sh 下的地址，改写后的镜像地址不受前端 http scope 限制
+#[tauri::command]
+pub async fn http_get_text(url: String) -> CommandResult<String> {
+    let url = url.trim();
+    if !ALLOWED_PREFIXES.iter().any(|p| url.starts_with(p) || format!("{url}/") == *p) {
+        return Err(CommandError::invalid("url", format!("不允许请求该地址: {url}")));
+    }
+    let network = load_settings().network;
+    let target = network.rewrite_url(url);
+    let client = build_client(&network, &target)?;
+    let response = client.get(&target).send().await.map_err(|e| http_error(&target, None, e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(http_error(&target, Some(status.as_u16()), status));
+    }
+    response.text().await.map_err(|e| http_error(&target, Some(status.as_u16()), e))
+}
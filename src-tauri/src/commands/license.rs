@@ -0,0 +1,272 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::frontmatter::parse_skill_md;
+use crate::commands::fs::find_skill_md;
+
+/// 识别出的许可证信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LicenseInfo {
+    /// SPDX 标识，如 MIT / Apache-2.0；无法识别时为 None
+    pub spdx: Option<String>,
+    /// 来源：frontmatter 或许可证文件的相对路径（repo 根目录文件以 repo:/ 开头）
+    pub source: Option<String>,
+    /// 是否被许可证策略标记
+    #[serde(default)]
+    pub flagged: bool,
+    pub flag_reason: Option<String>,
+    /// 无法查找 repo 根目录许可证文件时的原因（此时 source 为 unknown）
+    #[serde(default)]
+    pub unresolved_reason: Option<String>,
+}
+
+/// 安装时的许可证策略
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LicensePolicy {
+    /// 允许的 SPDX 标识；为空表示不限制
+    pub allowed: Vec<String>,
+    /// 是否允许无法识别的许可证
+    pub allow_unknown: bool,
+    /// 命中时是否中止安装（否则仅标记）
+    pub block: bool,
+}
+
+impl Default for LicensePolicy {
+    fn default() -> Self {
+        Self {
+            allowed: vec![],
+            allow_unknown: true,
+            block: false,
+        }
+    }
+}
+
+const LICENSE_FILE_NAMES: &[&str] = &[
+    "LICENSE", "LICENSE.md", "LICENSE.txt", "LICENCE", "LICENCE.md", "LICENCE.txt", "COPYING", "COPYING.md",
+    "LICENSE-MIT", "LICENSE-APACHE",
+];
+
+const KNOWN_SPDX: &[&str] = &[
+    "MIT", "Apache-2.0", "BSD-2-Clause", "BSD-3-Clause", "ISC", "MPL-2.0", "GPL-2.0", "GPL-3.0", "LGPL-2.1",
+    "LGPL-3.0", "AGPL-3.0", "Unlicense", "CC0-1.0", "CC-BY-4.0", "CC-BY-SA-4.0", "BSL-1.0", "0BSD",
+];
+
+/// 规范化已知 SPDX 标识（大小写不敏感，兼容 -only/-or-later 后缀）
+fn normalize_spdx(raw: &str) -> Option<String> {
+    let v = raw.trim().trim_matches('"').trim_matches('\'');
+    let base = v.trim_end_matches("-only").trim_end_matches("-or-later").trim_end_matches('+');
+    KNOWN_SPDX
+        .iter()
+        .find(|k| k.eq_ignore_ascii_case(base))
+        .map(|k| {
+            if v.len() > base.len() {
+                format!("{k}{}", &v[base.len()..])
+            } else {
+                (*k).to_string()
+            }
+        })
+}
+
+/// 根据许可证全文识别 SPDX 标识
+pub(crate) fn identify_license_text(text: &str) -> Option<String> {
+    for line in text.lines().take(20) {
+        if let Some((_, id)) = line.split_once("SPDX-License-Identifier:") {
+            if let Some(spdx) = normalize_spdx(id) {
+                return Some(spdx);
+            }
+        }
+    }
+
+    let t = text.to_lowercase().split_whitespace().collect::<Vec<_>>().join(" ");
+    let id = if t.contains("gnu affero general public license") {
+        "AGPL-3.0"
+    } else if t.contains("gnu lesser general public license") {
+        if t.contains("version 2.1") {
+            "LGPL-2.1"
+        } else {
+            "LGPL-3.0"
+        }
+    } else if t.contains("gnu general public license") {
+        if t.contains("version 2") && !t.contains("version 3") {
+            "GPL-2.0"
+        } else {
+            "GPL-3.0"
+        }
+    } else if t.contains("apache license") && t.contains("version 2.0") {
+        "Apache-2.0"
+    } else if t.contains("mozilla public license") && t.contains("2.0") {
+        "MPL-2.0"
+    } else if t.contains("permission is hereby granted, free of charge") {
+        "MIT"
+    } else if t.contains("redistribution and use in source and binary forms") {
+        if t.contains("neither the name") {
+            "BSD-3-Clause"
+        } else {
+            "BSD-2-Clause"
+        }
+    } else if t.contains("permission to use, copy, modify, and/or distribute this software") {
+        if t.contains("copyright notice and this permission notice appear") {
+            "ISC"
+        } else {
+            "0BSD"
+        }
+    } else if t.contains("this is free and unencumbered software released into the public domain") {
+        "Unlicense"
+    } else if t.contains("cc0 1.0 universal") {
+        "CC0-1.0"
+    } else if t.contains("attribution-sharealike 4.0 international") {
+        "CC-BY-SA-4.0"
+    } else if t.contains("attribution 4.0 international") {
+        "CC-BY-4.0"
+    } else if t.contains("boost software license") {
+        "BSL-1.0"
+    } else {
+        return None;
+    };
+    Some(id.to_string())
+}
+
+fn find_license_file(dir: &Path) -> Option<(String, String)> {
+    LICENSE_FILE_NAMES.iter().find_map(|name| {
+        let p = dir.join(name);
+        if !p.is_file() {
+            return None;
+        }
+        fs::read_to_string(&p).ok().map(|text| ((*name).to_string(), text))
+    })
+}
+
+/// 识别 skill 许可证：frontmatter `license:`（若为已知 SPDX）> skill 目录许可证文件 > repo 根目录许可证文件
+pub(crate) fn detect_license(skill_dir: &Path, repo_root: Option<&Path>) -> LicenseInfo {
+    let frontmatter = find_skill_md(skill_dir, 3)
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|md| parse_skill_md(&md).str_field("license"));
+
+    if let Some(spdx) = frontmatter.as_deref().and_then(normalize_spdx) {
+        return LicenseInfo {
+            spdx: Some(spdx),
+            source: Some("frontmatter".into()),
+            ..Default::default()
+        };
+    }
+
+    if let Some((name, text)) = find_license_file(skill_dir) {
+        return LicenseInfo {
+            spdx: identify_license_text(&text),
+            source: Some(name),
+            ..Default::default()
+        };
+    }
+
+    if let Some((name, text)) = repo_root.and_then(find_license_file) {
+        return LicenseInfo {
+            spdx: identify_license_text(&text),
+            source: Some(format!("repo:/{name}")),
+            ..Default::default()
+        };
+    }
+
+    // frontmatter 写了非 SPDX 文本（如 "Proprietary"），保留来源便于人工判断
+    LicenseInfo {
+        spdx: None,
+        source: frontmatter.map(|_| "frontmatter".to_string()),
+        ..Default::default()
+    }
+}
+
+/// 按策略标记许可证；策略要求中止时返回错误
+pub(crate) fn apply_policy(info: &mut LicenseInfo, policy: &LicensePolicy) -> CommandResult<()> {
+    let reason = match &info.spdx {
+        None if !policy.allow_unknown => Some("无法识别许可证".to_string()),
+        Some(spdx)
+            if !policy.allowed.is_empty() && !policy.allowed.iter().any(|a| a.eq_ignore_ascii_case(spdx)) =>
+        {
+            Some(format!("许可证 {spdx} 不在允许列表中"))
+        }
+        _ => None,
+    };
+    let Some(reason) = reason else {
+        return Ok(());
+    };
+    if policy.block {
+        return Err(CommandError::LicenseRejected { spdx: info.spdx.clone(), reason });
+    }
+    info.flagged = true;
+    info.flag_reason = Some(reason);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIT: &str = "MIT License\n\nCopyright (c) 2024 Someone\n\nPermission is hereby granted, free of charge, to any person obtaining a copy\nof this software";
+
+    #[test]
+    fn identifies_common_license_texts() {
+        assert_eq!(identify_license_text(MIT).as_deref(), Some("MIT"));
+        let apache = "                                 Apache License\n                           Version 2.0, January 2004";
+        assert_eq!(identify_license_text(apache).as_deref(), Some("Apache-2.0"));
+        let gpl2 = "GNU GENERAL PUBLIC LICENSE\nVersion 2, June 1991";
+        assert_eq!(identify_license_text(gpl2).as_deref(), Some("GPL-2.0"));
+        let bsd3 = "Redistribution and use in source and binary forms ...\nNeither the name of the copyright holder";
+        assert_eq!(identify_license_text(bsd3).as_deref(), Some("BSD-3-Clause"));
+        assert_eq!(identify_license_text("All rights reserved."), None);
+    }
+
+    #[test]
+    fn spdx_header_takes_precedence() {
+        let text = "// SPDX-License-Identifier: apache-2.0\nPermission is hereby granted, free of charge";
+        assert_eq!(identify_license_text(text).as_deref(), Some("Apache-2.0"));
+        assert_eq!(identify_license_text("SPDX-License-Identifier: GPL-3.0-or-later").as_deref(), Some("GPL-3.0-or-later"));
+    }
+
+    #[test]
+    fn detect_prefers_frontmatter_then_skill_dir_then_repo_root() {
+        let repo = tempfile::tempdir().unwrap();
+        let skill = repo.path().join("skills/demo");
+        fs::create_dir_all(&skill).unwrap();
+        fs::write(repo.path().join("LICENSE"), MIT).unwrap();
+        fs::write(skill.join("SKILL.md"), "---\nname: demo\n---\n").unwrap();
+
+        let info = detect_license(&skill, Some(repo.path()));
+        assert_eq!((info.spdx.as_deref(), info.source.as_deref()), (Some("MIT"), Some("repo:/LICENSE")));
+        assert_eq!(detect_license(&skill, None).source, None);
+
+        fs::write(skill.join("COPYING"), "GNU GENERAL PUBLIC LICENSE\nVersion 3").unwrap();
+        let info = detect_license(&skill, Some(repo.path()));
+        assert_eq!((info.spdx.as_deref(), info.source.as_deref()), (Some("GPL-3.0"), Some("COPYING")));
+
+        fs::write(skill.join("SKILL.md"), "---\nname: demo\nlicense: isc\n---\n").unwrap();
+        let info = detect_license(&skill, Some(repo.path()));
+        assert_eq!((info.spdx.as_deref(), info.source.as_deref()), (Some("ISC"), Some("frontmatter")));
+    }
+
+    #[test]
+    fn non_spdx_frontmatter_keeps_source() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("SKILL.md"), "---\nname: demo\nlicense: Proprietary\n---\n").unwrap();
+        let info = detect_license(dir.path(), None);
+        assert_eq!((info.spdx, info.source.as_deref()), (None, Some("frontmatter")));
+    }
+
+    #[test]
+    fn policy_flags_or_blocks() {
+        let policy = LicensePolicy { allowed: vec!["MIT".into()], allow_unknown: false, block: false };
+        let mut info = LicenseInfo { spdx: Some("GPL-3.0".into()), ..Default::default() };
+        apply_policy(&mut info, &policy).unwrap();
+        assert!(info.flagged);
+
+        let mut info = LicenseInfo { spdx: Some("mit".into()), ..Default::default() };
+        apply_policy(&mut info, &policy).unwrap();
+        assert!(!info.flagged);
+
+        let policy = LicensePolicy { block: true, ..policy };
+        let mut info = LicenseInfo::default();
+        assert!(matches!(apply_policy(&mut info, &policy), Err(CommandError::LicenseRejected { spdx: None, .. })));
+    }
+}
@@ -0,0 +1,130 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::error::{CommandError, CommandResult};
+
+/// 安装限额（总大小 / 文件数 / 单文件大小 / 禁止的文件类型）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct InstallLimits {
+    /// 总字节数上限
+    pub max_total_bytes: u64,
+    /// 文件数上限
+    pub max_file_count: u64,
+    /// 单个文件字节数上限
+    pub max_file_bytes: u64,
+    /// 禁止的扩展名（小写、不含点），如 exe、dll、mp4
+    pub denied_extensions: Vec<String>,
+}
+
+impl Default for InstallLimits {
+    fn default() -> Self {
+        Self {
+            max_total_bytes: 50 * 1024 * 1024,
+            max_file_count: 2000,
+            max_file_bytes: 10 * 1024 * 1024,
+            denied_extensions: [
+                // 可执行文件 / 二进制库
+                "exe", "dll", "so", "dylib", "bin", "msi", "com", "scr", "app", "dmg", "pkg", "deb", "rpm",
+                "apk", "jar", "class", "o", "a", "lib",
+                // 大体积媒体
+                "mp4", "mov", "avi", "mkv", "webm", "mp3", "wav", "flac", "iso", "img",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+        }
+    }
+}
+
+/// 超限的限额项
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitKind {
+    TotalBytes,
+    FileCount,
+    FileBytes,
+}
+
+impl LimitKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::TotalBytes => "总大小（字节）",
+            Self::FileCount => "文件数",
+            Self::FileBytes => "单个文件大小（字节）",
+        }
+    }
+}
+
+/// 逐个文件检查暂存目录，命中任一限额即返回说明是哪项超限的错误（忽略 .git）
+pub(crate) fn check_staged_tree(dir: &Path, limits: &InstallLimits) -> CommandResult<()> {
+    let mut total_bytes = 0u64;
+    let mut file_count = 0u64;
+    walk(dir, dir, limits, &mut total_bytes, &mut file_count)
+}
+
+fn walk(
+    root: &Path,
+    dir: &Path,
+    limits: &InstallLimits,
+    total_bytes: &mut u64,
+    file_count: &mut u64,
+) -> CommandResult<()> {
+    let rd = fs::read_dir(dir).map_err(|e| CommandError::io("读取目录", dir, e))?;
+    for ent in rd.flatten() {
+        let path = ent.path();
+        // 符号链接不跟随，避免越出暂存目录
+        let Ok(meta) = fs::symlink_metadata(&path) else {
+            continue;
+        };
+        if meta.is_dir() {
+            if ent.file_name() == ".git" {
+                continue;
+            }
+            walk(root, &path, limits, total_bytes, file_count)?;
+            continue;
+        }
+
+        let rel = path.strip_prefix(root).unwrap_or(&path).display().to_string();
+
+        if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+            let ext = ext.to_lowercase();
+            if limits.denied_extensions.iter().any(|d| d.trim_start_matches('.').eq_ignore_ascii_case(&ext)) {
+                return Err(CommandError::DeniedFileType { extension: ext, path: rel });
+            }
+        }
+
+        *file_count += 1;
+        if *file_count > limits.max_file_count {
+            return Err(CommandError::LimitExceeded {
+                limit: LimitKind::FileCount,
+                path: None,
+                actual: *file_count,
+                max: limits.max_file_count,
+            });
+        }
+
+        let size = meta.len();
+        if size > limits.max_file_bytes {
+            return Err(CommandError::LimitExceeded {
+                limit: LimitKind::FileBytes,
+                path: Some(rel),
+                actual: size,
+                max: limits.max_file_bytes,
+            });
+        }
+
+        *total_bytes += size;
+        if *total_bytes > limits.max_total_bytes {
+            return Err(CommandError::LimitExceeded {
+                limit: LimitKind::TotalBytes,
+                path: None,
+                actual: *total_bytes,
+                max: limits.max_total_bytes,
+            });
+        }
+    }
+    Ok(())
+}
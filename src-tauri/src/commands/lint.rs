@@ -0,0 +1,338 @@
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::frontmatter::{parse_skill_md, DiagnosticSeverity, ParsedSkillMd};
+
+/// name 最大长度
+const MAX_NAME_CHARS: usize = 64;
+/// description 最大长度
+pub(crate) const MAX_DESCRIPTION_CHARS: usize = 1024;
+/// description 过短时提示（agent 依赖它判断何时使用 skill）
+const MIN_DESCRIPTION_CHARS: usize = 20;
+/// SKILL.md 正文建议上限（行数 / 字节），超出的内容应拆到 references/
+const MAX_BODY_LINES: usize = 500;
+const MAX_BODY_BYTES: usize = 40 * 1024;
+
+/// 规范定义的 frontmatter 键（tags 为 SkillHub 使用的扩展）
+const KNOWN_KEYS: &[&str] = &[
+    "name",
+    "description",
+    "license",
+    "allowed-tools",
+    "metadata",
+    "compatibility",
+    "version",
+    "disable-model-invocation",
+    "tags",
+];
+
+/// 正文中会校验相对链接的目录
+const LINKED_DIRS: &[&str] = &["references/", "scripts/", "assets/"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintDiagnostic {
+    /// 规则标识，如 name-format / broken-link
+    pub rule: &'static str,
+    pub severity: DiagnosticSeverity,
+    /// 相对 skill 根目录的文件路径
+    pub file: String,
+    /// 行号（从 1 开始）；与具体行无关时为 None
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LintReport {
+    /// 按文件、行号排序
+    pub diagnostics: Vec<LintDiagnostic>,
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+struct Linter {
+    diagnostics: Vec<LintDiagnostic>,
+}
+
+impl Linter {
+    fn push(&mut self, rule: &'static str, severity: DiagnosticSeverity, file: &str, line: Option<usize>, message: impl Into<String>) {
+        self.diagnostics.push(LintDiagnostic { rule, severity, file: file.to_string(), line, message: message.into() });
+    }
+
+    fn error(&mut self, rule: &'static str, line: Option<usize>, message: impl Into<String>) {
+        self.push(rule, DiagnosticSeverity::Error, "SKILL.md", line, message);
+    }
+
+    fn warning(&mut self, rule: &'static str, line: Option<usize>, message: impl Into<String>) {
+        self.push(rule, DiagnosticSeverity::Warning, "SKILL.md", line, message);
+    }
+}
+
+/// name 格式：小写字母、数字、连字符，不以连字符开头或结尾，不含连续连字符
+fn name_format_problem(name: &str) -> Option<&'static str> {
+    if !name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+        return Some("只能包含小写字母、数字和连字符");
+    }
+    if name.starts_with('-') || name.ends_with('-') {
+        return Some("不能以连字符开头或结尾");
+    }
+    if name.contains("--") {
+        return Some("不能包含连续的连字符");
+    }
+    None
+}
+
+fn lint_frontmatter(l: &mut Linter, parsed: &ParsedSkillMd, dir_name: &str) {
+    for d in &parsed.diagnostics {
+        l.push("frontmatter", d.severity, "SKILL.md", d.line, d.message.clone());
+    }
+    let Some(fields) = &parsed.fields else {
+        return;
+    };
+
+    let name_line = parsed.key_line("name");
+    match parsed.str_field("name") {
+        None => l.error("name-missing", name_line.or(Some(1)), "缺少 name 字段"),
+        Some(name) => {
+            if name.chars().count() > MAX_NAME_CHARS {
+                l.error("name-length", name_line, format!("name 超过 {MAX_NAME_CHARS} 个字符"));
+            }
+            if let Some(problem) = name_format_problem(&name) {
+                l.error("name-format", name_line, format!("name「{name}」{problem}"));
+            }
+            if name != dir_name {
+                l.error("name-mismatch", name_line, format!("name「{name}」与目录名「{dir_name}」不一致"));
+            }
+        }
+    }
+
+    let desc_line = parsed.key_line("description");
+    match parsed.str_field("description") {
+        None => l.error("description-missing", desc_line.or(Some(1)), "缺少 description 字段"),
+        Some(desc) => {
+            let count = desc.chars().count();
+            if count > MAX_DESCRIPTION_CHARS {
+                l.error("description-length", desc_line, format!("description 超过 {MAX_DESCRIPTION_CHARS} 个字符（当前 {count}）"));
+            } else if count < MIN_DESCRIPTION_CHARS {
+                l.warning("description-length", desc_line, "description 过短，应说明 skill 做什么以及何时使用");
+            }
+        }
+    }
+
+    for key in fields.keys().filter(|k| !KNOWN_KEYS.contains(&k.as_str())) {
+        l.warning("unknown-key", parsed.key_line(key), format!("未知的 frontmatter 字段「{key}」，自定义信息请放到 metadata 下"));
+    }
+}
+
+/// 提取一行中 Markdown 链接 `[text](target)` 的目标
+fn link_targets(line: &str) -> Vec<&str> {
+    let mut out = vec![];
+    let mut rest = line;
+    while let Some(idx) = rest.find("](") {
+        rest = &rest[idx + 2..];
+        let Some(end) = rest.find(')') else {
+            break;
+        };
+        let target = rest[..end].split_whitespace().next().unwrap_or("");
+        out.push(target.trim_matches(|c| c == '<' || c == '>'));
+        rest = &rest[end..];
+    }
+    out
+}
+
+fn lint_body(l: &mut Linter, parsed: &ParsedSkillMd, root: &Path) {
+    let lines = parsed.body.lines().count();
+    if lines > MAX_BODY_LINES {
+        l.warning("body-size", Some(parsed.body_line), format!("正文 {lines} 行，超过建议的 {MAX_BODY_LINES} 行，详细内容应拆分到 references/"));
+    } else if parsed.body.len() > MAX_BODY_BYTES {
+        l.warning("body-size", Some(parsed.body_line), format!("正文超过 {} KB，详细内容应拆分到 references/", MAX_BODY_BYTES / 1024));
+    }
+
+    // 当前所在代码块的围栏（``` 或 ~~~），只有同类围栏才能关闭
+    let mut fence: Option<&str> = None;
+    for (idx, line) in parsed.body.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+            match fence {
+                None => fence = Some(marker),
+                Some(open) if open == marker => fence = None,
+                Some(_) => {}
+            }
+            continue;
+        }
+        if fence.is_some() {
+            continue;
+        }
+        for target in link_targets(line) {
+            let rel = target.strip_prefix("./").unwrap_or(target);
+            let rel = rel.split(['#', '?']).next().unwrap_or(rel);
+            if !LINKED_DIRS.iter().any(|d| rel.starts_with(d)) {
+                continue;
+            }
+            if rel.split('/').any(|c| c == "..") || !root.join(rel).exists() {
+                l.error("broken-link", Some(parsed.body_line + idx), format!("链接的文件不存在: {target}"));
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(meta: &fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    meta.permissions().mode() & 0o111 != 0
+}
+
+fn lint_scripts(l: &mut Linter, root: &Path) {
+    let Ok(rd) = fs::read_dir(root.join("scripts")) else {
+        return;
+    };
+    let mut files: Vec<_> = rd.flatten().collect();
+    files.sort_by_key(|e| e.file_name());
+    for ent in files {
+        let Ok(meta) = ent.metadata() else {
+            continue;
+        };
+        if !meta.is_file() {
+            continue;
+        }
+        let file = format!("scripts/{}", ent.file_name().to_string_lossy());
+        #[cfg(unix)]
+        if !is_executable(&meta) {
+            l.push("script-not-executable", DiagnosticSeverity::Warning, &file, None, "脚本没有可执行权限（chmod +x）");
+        }
+        let mut head = [0u8; 2];
+        let has_shebang = fs::File::open(ent.path()).and_then(|mut f| f.read_exact(&mut head)).is_ok() && &head == b"#!";
+        let is_script = [".sh", ".py", ".bash", ".zsh"].iter().any(|ext| file.ends_with(ext));
+        if is_script && !has_shebang {
+            l.push("script-shebang", DiagnosticSeverity::Info, &file, Some(1), "脚本缺少 shebang（#!）");
+        }
+    }
+}
+
+/// 检查 skill 目录
+fn lint_dir(root: &Path) -> LintReport {
+    let mut l = Linter { diagnostics: vec![] };
+    let dir_name = root.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    match fs::read_to_string(root.join("SKILL.md")) {
+        Ok(md) => {
+            let parsed = parse_skill_md(&md);
+            lint_frontmatter(&mut l, &parsed, &dir_name);
+            lint_body(&mut l, &parsed, root);
+        }
+        Err(_) => l.error("skill-md-missing", None, "skill 根目录下缺少 SKILL.md"),
+    }
+    lint_scripts(&mut l, root);
+
+    let mut diagnostics = l.diagnostics;
+    diagnostics.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)).then(b.severity.cmp(&a.severity)));
+    LintReport {
+        errors: diagnostics.iter().filter(|d| d.severity == DiagnosticSeverity::Error).count(),
+        warnings: diagnostics.iter().filter(|d| d.severity == DiagnosticSeverity::Warning).count(),
+        diagnostics,
+    }
+}
+
+/// 检查 skill 的 SKILL.md 与目录结构（已安装 skill 或本地编写中的 skill 目录）
+#[tauri::command]
+pub fn lint_skill(path: String) -> CommandResult<LintReport> {
+    let path = Path::new(path.trim());
+    if !path.is_dir() {
+        return Err(CommandError::not_found(path));
+    }
+    Ok(lint_dir(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESCRIPTION: &str = "Formats release notes and explains when to use it.";
+
+    fn lint(dir_name: &str, skill_md: &str, extra: &[&str]) -> LintReport {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().join(dir_name);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("SKILL.md"), skill_md).unwrap();
+        for rel in extra {
+            let path = root.join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        lint_dir(&root)
+    }
+
+    fn rules(report: &LintReport) -> Vec<&'static str> {
+        report.diagnostics.iter().map(|d| d.rule).collect()
+    }
+
+    #[test]
+    fn valid_skill_has_no_diagnostics() {
+        let md = format!("---\nname: notes\ndescription: {DESCRIPTION}\nversion: 1.0.0\ndisable-model-invocation: true\n---\nSee [guide](references/guide.md).\n");
+        let report = lint("notes", &md, &["references/guide.md"]);
+        assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+    }
+
+    #[test]
+    fn name_rules() {
+        assert_eq!(name_format_problem("good-name-2"), None);
+        for bad in ["Bad", "-lead", "trail-", "double--dash", "under_score"] {
+            assert!(name_format_problem(bad).is_some(), "{bad}");
+        }
+        let md = format!("---\nname: other\ndescription: {DESCRIPTION}\n---\n");
+        let report = lint("notes", &md, &[]);
+        assert_eq!(rules(&report), vec!["name-mismatch"]);
+        assert_eq!(report.diagnostics[0].line, Some(2));
+    }
+
+    #[test]
+    fn missing_and_short_fields() {
+        let report = lint("notes", "---\nname: notes\n---\n", &[]);
+        assert_eq!(rules(&report), vec!["description-missing"]);
+        let report = lint("notes", "---\nname: notes\ndescription: short\n---\n", &[]);
+        assert_eq!((report.errors, report.warnings), (0, 1));
+    }
+
+    #[test]
+    fn unknown_keys_warn() {
+        let md = format!("---\nname: notes\ndescription: {DESCRIPTION}\nauthor: me\n---\n");
+        let report = lint("notes", &md, &[]);
+        assert_eq!(rules(&report), vec!["unknown-key"]);
+        assert_eq!(report.diagnostics[0].line, Some(4));
+    }
+
+    #[test]
+    fn broken_links_outside_code_blocks() {
+        let md = format!(
+            "---\nname: notes\ndescription: {DESCRIPTION}\n---\n[a](references/missing.md)\n[b](./scripts/../x.sh)\n```\n[c](references/in-fence.md)\n```\n~~~md\n[d](assets/in-tilde.png)\n```\n[e](assets/still-fenced.png)\n~~~\n[f](https://example.com)\n"
+        );
+        let report = lint("notes", &md, &[]);
+        let lines: Vec<_> = report.diagnostics.iter().map(|d| (d.rule, d.line)).collect();
+        assert_eq!(lines, vec![("broken-link", Some(5)), ("broken-link", Some(6))]);
+    }
+
+    #[test]
+    fn links_in_markdown_lines() {
+        assert_eq!(link_targets("see [a](references/a.md) and [b](<scripts/b.sh> \"title\")"), vec!["references/a.md", "scripts/b.sh"]);
+        assert!(link_targets("no links here").is_empty());
+    }
+
+    #[test]
+    fn missing_skill_md_is_an_error() {
+        let tmp = tempfile::tempdir().unwrap();
+        let report = lint_dir(tmp.path());
+        assert_eq!(rules(&report), vec!["skill-md-missing"]);
+        assert_eq!(report.errors, 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn scripts_need_exec_bit_and_shebang() {
+        let md = format!("---\nname: notes\ndescription: {DESCRIPTION}\n---\n");
+        let report = lint("notes", &md, &["scripts/run.sh"]);
+        let found: Vec<_> = report.diagnostics.iter().map(|d| (d.rule, d.file.as_str())).collect();
+        assert_eq!(found, vec![("script-not-executable", "scripts/run.sh"), ("script-shebang", "scripts/run.sh")]);
+    }
+}
@@ -1,11 +1,37 @@
 use serde::{Deserialize, Serialize};
 
+use error::CommandResult;
+
 pub mod db;
+pub mod detect;
+pub mod doctor;
+pub mod edit;
+pub mod error;
+pub mod export;
+pub mod files;
+pub mod frontmatter;
 pub mod fs;
 pub mod git;
+pub mod http;
+pub mod license;
+pub mod lint;
+pub mod limits;
+pub mod platforms;
+pub mod profiles;
+pub mod projects;
+pub mod record;
+pub mod repair;
+pub mod replicate;
+pub mod retry;
+pub mod scaffold;
+pub mod scan;
+pub mod settings;
+pub mod toggle;
+pub mod watcher;
+pub mod workspace;
 
 #[tauri::command]
-pub async fn greet(name: &str) -> Result<String, String> {
+pub async fn greet(name: &str) -> CommandResult<String> {
     Ok(format!("Hello, {}!", name))
 }
 
@@ -14,10 +40,28 @@ pub struct InstallSkillPayload {
     pub id: String,
     pub repo: String,
     pub sub_path: Option<String>,
-    /// 目标平台 (claude/cursor/antigravity/gemini)，默认 claude
+    /// 目标平台 (claude/antigravity/gemini/cursor/codex/opencode/windsurf/copilot 或自定义平台)，默认 claude
     pub target_platform: Option<String>,
     /// 项目根目录（可选），如有则安装到项目级路径
     pub project_root: Option<String>,
+    /// 安装限额（可选），缺省使用 InstallLimits::default()
+    pub limits: Option<limits::InstallLimits>,
+    /// 安全扫描策略（可选），缺省 High 及以上需确认
+    pub scan_policy: Option<scan::ScanPolicy>,
+    /// 许可证策略（可选），缺省不标记
+    pub license_policy: Option<license::LicensePolicy>,
+}
+
+/// 单次安装结果
+#[derive(Debug, Serialize)]
+pub struct InstallOutcome {
+    pub message: String,
+    pub install_path: String,
+    /// 激活前对暂存内容的安全扫描报告
+    pub scan: scan::ScanReport,
+    pub license: license::LicenseInfo,
+    /// 失败后被重试的尝试记录（无重试时为空）
+    pub attempts: Vec<retry::AttemptRecord>,
 }
 
 /// 一键安装结果
@@ -29,12 +73,18 @@ pub struct InstallAllResult {
 
 /// 安装 Skill 到本地：优先 npx skills add，失败则回退到 git sparse checkout
 #[tauri::command]
-pub async fn install_skill(payload: InstallSkillPayload) -> Result<String, String> {
-    git::install_skill_impl(payload).await
+pub async fn install_skill(payload: InstallSkillPayload) -> CommandResult<InstallOutcome> {
+    let project_root = payload.project_root.clone().filter(|p| !p.trim().is_empty());
+    let outcome = retry::run_blocking(move || git::install_skill_impl(payload)).await?;
+    // 记住安装过的项目；登记失败不影响安装结果
+    if let Some(root) = project_root {
+        let _ = projects::remember_project(std::path::Path::new(&root), projects::ProjectSource::Install);
+    }
+    Ok(outcome)
 }
 
 /// 一键安装到所有已检测到的平台（仅全局）
 #[tauri::command]
-pub async fn install_skill_to_all_platforms(payload: InstallSkillPayload) -> Result<InstallAllResult, String> {
-    git::install_skill_to_all_platforms_impl(payload).await
+pub async fn install_skill_to_all_platforms(payload: InstallSkillPayload) -> CommandResult<InstallAllResult> {
+    retry::run_blocking(move || git::install_skill_to_all_platforms_impl(payload)).await
 }
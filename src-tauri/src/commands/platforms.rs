@@ -0,0 +1,363 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::fs::home_dir;
+use crate::commands::settings::{load_settings, skillhub_dir};
+
+/// 平台支持的能力
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlatformFeature {
+    /// 安装到用户目录
+    GlobalInstall,
+    /// 安装到项目目录
+    ProjectInstall,
+    /// 可通过 npx skills add --agent 安装
+    NpxInstall,
+}
+
+/// 平台定义：agent 的检测方式、skills 目录与 skills CLI 映射。
+///
+/// 路径均以 `/` 分隔；`detect_path`（agent 配置目录）/ `global_dir` 相对用户目录（绝对路径则原样使用），
+/// `project_dir` 相对项目根目录。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlatformSpec {
+    pub id: String,
+    pub label: String,
+    /// 存在即视为已安装该 agent
+    pub detect_path: String,
+    pub global_dir: String,
+    pub project_dir: String,
+    /// skills CLI 的 --agent 名称；为空时仅使用 git 安装
+    #[serde(default)]
+    pub agent: Option<String>,
+    #[serde(default = "default_features")]
+    pub features: Vec<PlatformFeature>,
+    /// 可重定位配置目录的环境变量，按顺序取第一个已设置的，如 `$CLAUDE_CONFIG_DIR`、`$XDG_CONFIG_HOME/opencode`
+    #[serde(default)]
+    pub config_env: Vec<String>,
+    /// agent 可执行文件名（在 PATH 中查找）；为空时仅以配置目录判断是否安装
+    #[serde(default)]
+    pub binaries: Vec<String>,
+    /// 是否为内置平台（用户配置中的值会被忽略）
+    #[serde(default, skip_deserializing)]
+    pub builtin: bool,
+}
+
+fn default_features() -> Vec<PlatformFeature> {
+    vec![PlatformFeature::GlobalInstall, PlatformFeature::ProjectInstall, PlatformFeature::NpxInstall]
+}
+
+/// 内置平台定义
+struct Builtin {
+    id: &'static str,
+    label: &'static str,
+    detect_path: &'static str,
+    global_dir: &'static str,
+    project_dir: &'static str,
+    agent: &'static str,
+    config_env: &'static [&'static str],
+    binaries: &'static [&'static str],
+}
+
+const BUILTIN_PLATFORMS: &[Builtin] = &[
+    Builtin {
+        id: "claude",
+        label: "Claude Code",
+        detect_path: ".claude",
+        global_dir: ".claude/skills",
+        project_dir: ".claude/skills",
+        agent: "claude-code",
+        config_env: &["$CLAUDE_CONFIG_DIR"],
+        binaries: &["claude"],
+    },
+    Builtin {
+        id: "antigravity",
+        label: "Antigravity",
+        detect_path: ".gemini/antigravity",
+        global_dir: ".gemini/antigravity/skills",
+        project_dir: ".agent/skills",
+        agent: "antigravity",
+        config_env: &[],
+        binaries: &["antigravity"],
+    },
+    Builtin {
+        id: "gemini",
+        label: "Gemini CLI",
+        detect_path: ".gemini",
+        global_dir: ".gemini/skills",
+        project_dir: ".gemini/skills",
+        agent: "gemini-cli",
+        config_env: &[],
+        binaries: &["gemini"],
+    },
+    Builtin {
+        id: "cursor",
+        label: "Cursor",
+        detect_path: ".cursor",
+        global_dir: ".cursor/skills",
+        project_dir: ".cursor/skills",
+        agent: "cursor",
+        config_env: &[],
+        binaries: &["cursor-agent", "cursor"],
+    },
+    Builtin {
+        id: "codex",
+        label: "Codex CLI",
+        detect_path: ".codex",
+        global_dir: ".codex/skills",
+        project_dir: ".codex/skills",
+        agent: "codex",
+        config_env: &["$CODEX_HOME"],
+        binaries: &["codex"],
+    },
+    Builtin {
+        id: "opencode",
+        label: "OpenCode",
+        detect_path: ".config/opencode",
+        global_dir: ".config/opencode/skill",
+        project_dir: ".opencode/skill",
+        agent: "opencode",
+        config_env: &["$OPENCODE_CONFIG_DIR", "$XDG_CONFIG_HOME/opencode"],
+        binaries: &["opencode"],
+    },
+    Builtin {
+        id: "windsurf",
+        label: "Windsurf",
+        detect_path: ".codeium/windsurf",
+        global_dir: ".codeium/windsurf/skills",
+        project_dir: ".windsurf/skills",
+        agent: "windsurf",
+        config_env: &[],
+        binaries: &["windsurf"],
+    },
+    Builtin {
+        id: "copilot",
+        label: "GitHub Copilot",
+        detect_path: ".copilot",
+        global_dir: ".copilot/skills",
+        project_dir: ".github/skills",
+        agent: "github-copilot",
+        config_env: &[],
+        binaries: &["copilot"],
+    },
+];
+
+/// 用户自定义平台配置文件（JSON 数组，字段同 PlatformSpec；id 与内置平台相同时覆盖内置定义）
+fn user_platforms_path() -> CommandResult<PathBuf> {
+    Ok(skillhub_dir()?.join("platforms.json"))
+}
+
+fn join_rel(base: &Path, rel: &str) -> PathBuf {
+    rel.split('/').filter(|s| !s.is_empty()).fold(base.to_path_buf(), |p, s| p.join(s))
+}
+
+/// 路径来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PathSource {
+    /// agent 的环境变量
+    Env,
+    /// 应用设置中的平台路径覆盖
+    Settings,
+    /// 平台定义的默认路径
+    Default,
+}
+
+/// 解析后的目录及其来源
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedDir {
+    pub path: PathBuf,
+    pub source: PathSource,
+    /// 来源为环境变量时的变量名
+    pub env_var: Option<String>,
+}
+
+impl ResolvedDir {
+    fn new(path: PathBuf, source: PathSource) -> Self {
+        Self { path, source, env_var: None }
+    }
+}
+
+/// 各平台解析后的目录（用于设置页展示来源）
+#[derive(Debug, Clone, Serialize)]
+pub struct PlatformPaths {
+    pub id: String,
+    pub label: String,
+    pub config_dir: ResolvedDir,
+    pub global_dir: ResolvedDir,
+}
+
+/// 展开 `$VAR` 或 `$VAR/sub/dir`；变量未设置或为空时返回 None
+fn expand_env(entry: &str) -> Option<(String, PathBuf)> {
+    let rest = entry.trim().strip_prefix('$')?;
+    let (var, suffix) = rest.split_once('/').unwrap_or((rest, ""));
+    let value = std::env::var(var).ok().filter(|v| !v.trim().is_empty())?;
+    Some((var.to_string(), join_rel(Path::new(&value), suffix)))
+}
+
+impl PlatformSpec {
+    pub fn supports(&self, feature: PlatformFeature) -> bool {
+        self.features.contains(&feature)
+    }
+
+    fn home_path(rel: &str) -> CommandResult<PathBuf> {
+        if Path::new(rel).is_absolute() {
+            return Ok(PathBuf::from(rel));
+        }
+        Ok(join_rel(&home_dir()?, rel))
+    }
+
+    /// global_dir 相对配置目录的部分（如 .claude/skills -> skills）；不在配置目录下时为 None
+    fn skills_subdir(&self) -> Option<&str> {
+        let config = self.detect_path.trim_end_matches('/');
+        self.global_dir.strip_prefix(config).map(|s| s.trim_start_matches('/'))
+    }
+
+    /// 配置目录：环境变量 > 设置中的覆盖 > 默认
+    pub fn resolve_config_dir(&self) -> CommandResult<ResolvedDir> {
+        if let Some((var, path)) = self.config_env.iter().find_map(|e| expand_env(e)) {
+            return Ok(ResolvedDir { path, source: PathSource::Env, env_var: Some(var) });
+        }
+        let settings = load_settings();
+        if let Some(dir) = settings.platform_paths.get(&self.id).and_then(|o| o.config_dir()) {
+            return Ok(ResolvedDir::new(dir, PathSource::Settings));
+        }
+        Ok(ResolvedDir::new(Self::home_path(&self.detect_path)?, PathSource::Default))
+    }
+
+    /// 全局 skills 目录：环境变量（配置目录 + skills 子目录）> 设置中的 skills 目录 > 设置中的配置目录 > 默认
+    pub fn resolve_global_dir(&self) -> CommandResult<ResolvedDir> {
+        if !self.supports(PlatformFeature::GlobalInstall) {
+            return Err(CommandError::invalid("platform", format!("{} 不支持全局安装", self.id)));
+        }
+        let config = self.resolve_config_dir()?;
+        let from_config = self
+            .skills_subdir()
+            .map(|sub| ResolvedDir { path: join_rel(&config.path, sub), ..config.clone() });
+        if config.source == PathSource::Env {
+            if let Some(dir) = from_config {
+                return Ok(dir);
+            }
+        }
+        let settings = load_settings();
+        if let Some(dir) = settings.platform_paths.get(&self.id).and_then(|o| o.skills_dir()) {
+            return Ok(ResolvedDir::new(dir, PathSource::Settings));
+        }
+        if config.source == PathSource::Settings {
+            if let Some(dir) = from_config {
+                return Ok(dir);
+            }
+        }
+        Ok(ResolvedDir::new(Self::home_path(&self.global_dir)?, PathSource::Default))
+    }
+
+    pub fn detection_path(&self) -> CommandResult<PathBuf> {
+        Ok(self.resolve_config_dir()?.path)
+    }
+
+    pub fn global_skills_dir(&self) -> CommandResult<PathBuf> {
+        Ok(self.resolve_global_dir()?.path)
+    }
+
+    pub fn project_skills_dir(&self, project_root: &Path) -> CommandResult<PathBuf> {
+        if !self.supports(PlatformFeature::ProjectInstall) {
+            return Err(CommandError::invalid("platform", format!("{} 不支持项目级安装", self.id)));
+        }
+        Ok(join_rel(project_root, &self.project_dir))
+    }
+
+    /// 可用于 npx skills add 的 agent 名称
+    pub fn npx_agent(&self) -> Option<&str> {
+        self.agent
+            .as_deref()
+            .filter(|a| !a.trim().is_empty() && self.supports(PlatformFeature::NpxInstall))
+    }
+
+    /// 用户配置的基本校验：id 为简单标识，项目目录为不越出项目根的相对路径
+    fn is_valid(&self) -> bool {
+        let id_ok = !self.id.trim().is_empty()
+            && self.id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        let project_ok = !Path::new(&self.project_dir).is_absolute()
+            && !self.project_dir.split('/').any(|s| s == "..")
+            && !self.project_dir.trim().is_empty();
+        id_ok && project_ok && !self.global_dir.trim().is_empty() && !self.detect_path.trim().is_empty()
+    }
+}
+
+fn builtin_platforms() -> Vec<PlatformSpec> {
+    BUILTIN_PLATFORMS
+        .iter()
+        .map(|b| PlatformSpec {
+            id: b.id.to_string(),
+            label: b.label.to_string(),
+            detect_path: b.detect_path.to_string(),
+            global_dir: b.global_dir.to_string(),
+            project_dir: b.project_dir.to_string(),
+            agent: Some(b.agent.to_string()),
+            features: default_features(),
+            config_env: b.config_env.iter().map(|e| e.to_string()).collect(),
+            binaries: b.binaries.iter().map(|e| e.to_string()).collect(),
+            builtin: true,
+        })
+        .collect()
+}
+
+/// 读取用户自定义平台；文件不存在或损坏时为空，不合法的条目被跳过
+fn user_platforms() -> Vec<PlatformSpec> {
+    user_platforms_path()
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|text| serde_json::from_str::<Vec<PlatformSpec>>(&text).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(PlatformSpec::is_valid)
+        .collect()
+}
+
+/// 所有平台：内置平台在前（按表顺序），用户平台在后；同 id 时用户定义覆盖内置
+pub(crate) fn all_platforms() -> Vec<PlatformSpec> {
+    let mut platforms = builtin_platforms();
+    for spec in user_platforms() {
+        match platforms.iter_mut().find(|p| p.id == spec.id) {
+            Some(existing) => *existing = spec,
+            None => platforms.push(spec),
+        }
+    }
+    platforms
+}
+
+pub(crate) fn platform_spec(id: &str) -> CommandResult<PlatformSpec> {
+    let id = id.trim();
+    all_platforms()
+        .into_iter()
+        .find(|p| p.id.eq_ignore_ascii_case(id))
+        .ok_or_else(|| CommandError::UnsupportedPlatform { platform: id.to_string() })
+}
+
+/// 获取所有平台定义（内置 + ~/.skillhub/platforms.json）
+#[tauri::command]
+pub fn get_platforms() -> CommandResult<Vec<PlatformSpec>> {
+    Ok(all_platforms())
+}
+
+/// 获取各平台解析后的配置目录与全局 skills 目录及其来源
+#[tauri::command]
+pub fn get_platform_paths() -> CommandResult<Vec<PlatformPaths>> {
+    let mut out = vec![];
+    for spec in all_platforms() {
+        let Ok(global_dir) = spec.resolve_global_dir() else {
+            continue;
+        };
+        out.push(PlatformPaths {
+            id: spec.id.clone(),
+            label: spec.label.clone(),
+            config_dir: spec.resolve_config_dir()?,
+            global_dir,
+        });
+    }
+    Ok(out)
+}
@@ -0,0 +1,207 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::fs::{get_installed_skills, SkillScope};
+use crate::commands::git::{ensure_dir, validate_skill_id};
+use crate::commands::platforms::platform_spec;
+use crate::commands::record::now_secs;
+use crate::commands::settings::skillhub_dir;
+use crate::commands::toggle::toggle_skill;
+
+/// 档案中的一组 skill：某平台的全局目录或某个项目目录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileTarget {
+    pub platform: String,
+    /// 为空表示全局
+    #[serde(default)]
+    pub project_root: Option<String>,
+    /// 该目标下应启用的 skill；目标下其余 skill 将被停用
+    pub skills: Vec<String>,
+}
+
+/// 命名档案（持久化到 ~/.skillhub/profiles.json）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillProfile {
+    pub name: String,
+    pub targets: Vec<ProfileTarget>,
+    #[serde(default)]
+    pub updated_at: u64,
+}
+
+/// 单个 skill 的启停变更
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileChange {
+    pub platform: String,
+    pub scope: SkillScope,
+    pub project_root: Option<String>,
+    pub id: String,
+}
+
+/// 应用档案的结果
+#[derive(Debug, Default, Serialize)]
+pub struct ProfileDiff {
+    pub profile: String,
+    pub enabled: Vec<ProfileChange>,
+    pub disabled: Vec<ProfileChange>,
+    /// 档案中列出但未安装的 skill（无法启用，已跳过）
+    pub missing: Vec<ProfileChange>,
+    /// 仅计算差异，未实际执行
+    pub dry_run: bool,
+}
+
+fn profiles_path() -> CommandResult<PathBuf> {
+    Ok(skillhub_dir()?.join("profiles.json"))
+}
+
+fn load_profiles() -> Vec<SkillProfile> {
+    profiles_path()
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn write_profiles(profiles: &[SkillProfile]) -> CommandResult<()> {
+    let path = profiles_path()?;
+    if let Some(parent) = path.parent() {
+        ensure_dir(parent)?;
+    }
+    let text = serde_json::to_string_pretty(profiles)
+        .map_err(|e| CommandError::Serialization { reason: e.to_string() })?;
+    fs::write(&path, text).map_err(|e| CommandError::io("写入档案", &path, e))
+}
+
+impl SkillProfile {
+    fn validate(&self) -> CommandResult<()> {
+        let name = self.name.trim();
+        if name.is_empty() || name.chars().count() > 64 {
+            return Err(CommandError::invalid("name", "不能为空且不超过 64 个字符"));
+        }
+        for target in &self.targets {
+            platform_spec(&target.platform)?;
+            for id in &target.skills {
+                validate_skill_id(id)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 计算档案相对当前状态的差异
+fn plan(profile: &SkillProfile) -> CommandResult<ProfileDiff> {
+    let mut diff = ProfileDiff { profile: profile.name.clone(), ..Default::default() };
+    for target in &profile.targets {
+        let project_root = target.project_root.clone().filter(|p| !p.trim().is_empty());
+        let scope = if project_root.is_some() { SkillScope::Project } else { SkillScope::Global };
+        let change = |id: &str| ProfileChange {
+            platform: target.platform.clone(),
+            scope,
+            project_root: project_root.clone(),
+            id: id.to_string(),
+        };
+        let current: Vec<(String, bool)> = get_installed_skills(target.platform.clone(), project_root.clone())?
+            .into_iter()
+            .filter(|m| m.scope == scope)
+            .map(|m| (m.id, m.enabled))
+            .collect();
+
+        for (id, enabled) in &current {
+            let wanted = target.skills.iter().any(|s| s == id);
+            if wanted && !enabled {
+                diff.enabled.push(change(id));
+            } else if !wanted && *enabled {
+                diff.disabled.push(change(id));
+            }
+        }
+        for id in &target.skills {
+            if !current.iter().any(|(c, _)| c == id) {
+                diff.missing.push(change(id));
+            }
+        }
+    }
+    Ok(diff)
+}
+
+fn apply_change(c: &ProfileChange, enabled: bool) -> CommandResult<()> {
+    toggle_skill(c.platform.clone(), c.id.clone(), enabled, c.project_root.clone()).map(|_| ())
+}
+
+/// 获取所有档案
+#[tauri::command]
+pub fn get_profiles() -> CommandResult<Vec<SkillProfile>> {
+    Ok(load_profiles())
+}
+
+/// 新建或覆盖同名档案
+#[tauri::command]
+pub fn save_profile(mut profile: SkillProfile) -> CommandResult<SkillProfile> {
+    profile.validate()?;
+    profile.name = profile.name.trim().to_string();
+    profile.updated_at = now_secs();
+    let mut profiles = load_profiles();
+    match profiles.iter_mut().find(|p| p.name == profile.name) {
+        Some(existing) => *existing = profile.clone(),
+        None => profiles.push(profile.clone()),
+    }
+    write_profiles(&profiles)?;
+    Ok(profile)
+}
+
+/// 删除档案（不改变 skill 的启停状态）
+#[tauri::command]
+pub fn delete_profile(name: String) -> CommandResult<()> {
+    let mut profiles = load_profiles();
+    let before = profiles.len();
+    profiles.retain(|p| p.name != name);
+    if profiles.len() == before {
+        return Err(CommandError::invalid("name", format!("档案 {name} 不存在")));
+    }
+    write_profiles(&profiles)
+}
+
+/// 切换到档案：档案涉及的每个目标下，仅启用列出的 skill。
+/// 逐个执行启停，任一步失败则按相反顺序撤销已执行的变更，保证要么全部生效要么保持原状。
+#[tauri::command]
+pub fn apply_profile(name: String, dry_run: Option<bool>) -> CommandResult<ProfileDiff> {
+    let profile = load_profiles()
+        .into_iter()
+        .find(|p| p.name == name)
+        .ok_or_else(|| CommandError::invalid("name", format!("档案 {name} 不存在")))?;
+    let mut diff = plan(&profile)?;
+    diff.dry_run = dry_run.unwrap_or(false);
+    if diff.dry_run {
+        return Ok(diff);
+    }
+
+    // 先停用再启用，避免切换过程中两套 skill 同时可见
+    let steps: Vec<(&ProfileChange, bool)> = diff
+        .disabled
+        .iter()
+        .map(|c| (c, false))
+        .chain(diff.enabled.iter().map(|c| (c, true)))
+        .collect();
+    let mut done: Vec<(&ProfileChange, bool)> = vec![];
+    for (change, enabled) in steps {
+        if let Err(e) = apply_change(change, enabled) {
+            let rollback_failures: Vec<String> = done
+                .into_iter()
+                .rev()
+                .filter_map(|(undo, was_enabled)| {
+                    let action = if was_enabled { "停用" } else { "启用" };
+                    apply_change(undo, !was_enabled)
+                        .err()
+                        .map(|err| format!("{action} {}（{}）: {err}", undo.id, undo.platform))
+                })
+                .collect();
+            if rollback_failures.is_empty() {
+                return Err(e);
+            }
+            return Err(CommandError::RollbackIncomplete { error: Box::new(e), rollback_failures });
+        }
+        done.push((change, enabled));
+    }
+    Ok(diff)
+}
@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::fs::{read_skill_meta, InstalledSkillMeta, SkillScope};
+use crate::commands::platforms::all_platforms;
+use crate::commands::record::now_secs;
+use crate::commands::settings::skillhub_dir;
+
+/// 项目加入登记表的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectSource {
+    /// 安装 skill 到该项目时自动登记
+    Install,
+    /// 用户手动添加
+    Manual,
+    /// 工作区扫描发现
+    Scan,
+}
+
+/// 已知项目（持久化到 ~/.skillhub/projects.json）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnownProject {
+    /// 项目根目录（规范化后的绝对路径）
+    pub path: String,
+    /// 显示名称，默认取目录名
+    pub name: String,
+    pub source: ProjectSource,
+    /// 登记时间（Unix 秒）
+    pub added_at: u64,
+    /// 最近一次扫描 skills 的时间（Unix 秒）
+    pub last_scanned_at: Option<u64>,
+}
+
+/// 项目中某个平台下的 skill
+#[derive(Debug, Serialize)]
+pub struct ProjectSkill {
+    pub platform: String,
+    #[serde(flatten)]
+    pub meta: InstalledSkillMeta,
+}
+
+/// 单个项目的 skill 清单
+#[derive(Debug, Serialize)]
+pub struct ProjectInventory {
+    pub project: KnownProject,
+    /// 项目目录已不存在
+    pub missing: bool,
+    pub skills: Vec<ProjectSkill>,
+}
+
+fn projects_path() -> CommandResult<PathBuf> {
+    Ok(skillhub_dir()?.join("projects.json"))
+}
+
+/// 读取登记表；文件不存在或损坏时为空
+pub(crate) fn load_projects() -> Vec<KnownProject> {
+    projects_path()
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn write_projects(projects: &[KnownProject]) -> CommandResult<()> {
+    let path = projects_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| CommandError::io("创建目录", parent, e))?;
+    }
+    let text = serde_json::to_string_pretty(projects)
+        .map_err(|e| CommandError::Serialization { reason: e.to_string() })?;
+    fs::write(&path, text).map_err(|e| CommandError::io("写入项目列表", &path, e))
+}
+
+/// 规范化项目路径，用作登记表的唯一键
+fn normalize_project_path(path: &Path) -> CommandResult<PathBuf> {
+    if !path.is_dir() {
+        return Err(CommandError::not_found(path));
+    }
+    path.canonicalize().map_err(|e| CommandError::io("解析路径", path, e))
+}
+
+/// 登记项目（已存在则保持原记录），返回登记后的记录
+pub(crate) fn remember_project(path: &Path, source: ProjectSource) -> CommandResult<KnownProject> {
+    let path = normalize_project_path(path)?;
+    let key = path.display().to_string();
+    let mut projects = load_projects();
+    if let Some(existing) = projects.iter().find(|p| p.path == key) {
+        return Ok(existing.clone());
+    }
+    let project = KnownProject {
+        name: path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| key.clone()),
+        path: key,
+        source,
+        added_at: now_secs(),
+        last_scanned_at: None,
+    };
+    projects.push(project.clone());
+    write_projects(&projects)?;
+    Ok(project)
+}
+
+/// 扫描项目根目录下所有平台的项目级 skills 目录（多个平台共用同一目录时只计一次）
+pub(crate) fn scan_project_skills(root: &Path) -> Vec<ProjectSkill> {
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+    let mut skills = vec![];
+    for spec in all_platforms() {
+        let Ok(dir) = spec.project_skills_dir(root) else {
+            continue;
+        };
+        if !seen.insert(dir.clone()) {
+            continue;
+        }
+        let Ok(rd) = fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut entries: Vec<(String, PathBuf)> = rd
+            .flatten()
+            .filter(|e| e.path().is_dir())
+            .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
+            .collect();
+        entries.sort();
+        for (id, path) in entries {
+            skills.push(ProjectSkill {
+                platform: spec.id.clone(),
+                meta: read_skill_meta(id, &path, SkillScope::Project),
+            });
+        }
+    }
+    skills
+}
+
+/// 获取已登记的项目
+#[tauri::command]
+pub fn get_known_projects() -> CommandResult<Vec<KnownProject>> {
+    Ok(load_projects())
+}
+
+/// 手动登记项目
+#[tauri::command]
+pub fn add_known_project(path: String) -> CommandResult<KnownProject> {
+    if path.trim().is_empty() {
+        return Err(CommandError::invalid("path", "不能为空"));
+    }
+    remember_project(Path::new(path.trim()), ProjectSource::Manual)
+}
+
+/// 从登记表移除项目（不删除任何文件）
+#[tauri::command]
+pub fn remove_known_project(path: String) -> CommandResult<()> {
+    let mut projects = load_projects();
+    let before = projects.len();
+    projects.retain(|p| p.path != path);
+    if projects.len() == before {
+        return Err(CommandError::NotFound { path });
+    }
+    write_projects(&projects)
+}
+
+/// 获取项目的 skill 清单（path 为空时返回所有已登记项目），并更新扫描时间；目录已不存在的项目标记为 missing
+#[tauri::command]
+pub fn get_project_inventory(path: Option<String>) -> CommandResult<Vec<ProjectInventory>> {
+    let mut projects = load_projects();
+    let mut out = vec![];
+    for project in projects.iter_mut() {
+        if path.as_ref().is_some_and(|p| *p != project.path) {
+            continue;
+        }
+        let root = Path::new(&project.path);
+        let missing = !root.is_dir();
+        let skills = if missing {
+            vec![]
+        } else {
+            project.last_scanned_at = Some(now_secs());
+            scan_project_skills(root)
+        };
+        out.push(ProjectInventory { project: project.clone(), missing, skills });
+    }
+    if let Some(p) = path.filter(|_| out.is_empty()) {
+        return Err(CommandError::NotFound { path: p });
+    }
+    write_projects(&projects)?;
+    Ok(out)
+}
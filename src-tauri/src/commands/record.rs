@@ -0,0 +1,49 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::license::LicenseInfo;
+
+/// 安装记录文件名（写在 skill 目录内，随目录移动）
+pub const RECORD_FILE: &str = ".skillhub.json";
+
+/// 安装记录：来源、版本与许可证
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InstallRecord {
+    pub id: String,
+    pub repo: String,
+    pub sub_path: Option<String>,
+    pub platform: String,
+    pub project_root: Option<String>,
+    /// 安装方式：npx / git
+    pub method: String,
+    /// 安装时的 commit（npx 安装无法获取时为 None）
+    pub commit: Option<String>,
+    /// 安装时间（Unix 秒）
+    pub installed_at: u64,
+    pub license: Option<LicenseInfo>,
+    /// 在 SkillHub 内编辑过的时间（Unix 秒）；不为空时修复 / 更新需确认后才会覆盖
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_modified_at: Option<u64>,
+}
+
+pub(crate) fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+pub(crate) fn read_record(skill_dir: &Path) -> Option<InstallRecord> {
+    let text = fs::read_to_string(skill_dir.join(RECORD_FILE)).ok()?;
+    serde_json::from_str(&text).ok()
+}
+
+pub(crate) fn write_record(skill_dir: &Path, record: &InstallRecord) -> CommandResult<()> {
+    let path = skill_dir.join(RECORD_FILE);
+    let text = serde_json::to_string_pretty(record)
+        .map_err(|e| CommandError::Serialization { reason: e.to_string() })?;
+    fs::write(&path, text).map_err(|e| CommandError::io("写入安装记录", &path, e))
+}
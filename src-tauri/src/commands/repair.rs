@@ -0,0 +1,268 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::fs::is_valid_skills_path;
+use crate::commands::frontmatter::parse_skill_md;
+use crate::commands::git::{
+    git_output, normalize_repo_url, remove_dir_if_exists, stage_repo, unique_temp_dir, CLONE_TEMP_PREFIX,
+};
+use crate::commands::limits::{check_staged_tree, InstallLimits};
+use crate::commands::platforms::all_platforms;
+use crate::commands::record::{read_record, write_record, RECORD_FILE};
+use crate::commands::retry::{run_blocking, AttemptRecord};
+use crate::commands::scan::{enforce_policy, scan_dir, ScanPolicy};
+
+/// 单个 skill 的修复结果
+#[derive(Debug, Clone, Serialize)]
+pub struct RepairReport {
+    pub id: String,
+    pub install_path: String,
+    /// 用于还原的 commit
+    pub commit: Option<String>,
+    /// 缺失后被恢复的文件（相对 skill 目录）
+    pub restored: Vec<String>,
+    /// 内容被改动、已覆盖为原始内容的文件
+    pub replaced: Vec<String>,
+    /// 来源中不存在的本地文件（保留不删除）
+    pub extra: Vec<String>,
+    /// 安装记录中没有 commit，已按 force 更新到来源的最新版本（而非还原安装时的版本）
+    pub upgraded: bool,
+}
+
+/// 批量修复时单个 skill 的结果
+#[derive(Debug, Serialize)]
+pub struct RepairAllEntry {
+    pub install_path: String,
+    pub report: Option<RepairReport>,
+    pub error: Option<CommandError>,
+}
+
+/// 收集目录下所有普通文件的相对路径（忽略 .git 与安装记录；符号链接跳过，不读取其指向的内容）
+fn collect_files(root: &Path, dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(rd) = fs::read_dir(dir) else {
+        return;
+    };
+    for ent in rd.flatten() {
+        let path = ent.path();
+        let name = ent.file_name();
+        if name == ".git" || name == RECORD_FILE {
+            continue;
+        }
+        let Ok(meta) = fs::symlink_metadata(&path) else {
+            continue;
+        };
+        if meta.is_dir() {
+            collect_files(root, &path, out);
+        } else if !meta.is_file() {
+            continue;
+        } else if let Ok(rel) = path.strip_prefix(root) {
+            out.push(rel.to_path_buf());
+        }
+    }
+}
+
+fn rel_string(rel: &Path) -> String {
+    rel.to_string_lossy().replace('\\', "/")
+}
+
+/// 用来源目录覆盖安装目录中缺失或被改动的文件
+fn restore_tree(src: &Path, target: &Path, report: &mut RepairReport) -> CommandResult<()> {
+    let mut src_files = vec![];
+    collect_files(src, src, &mut src_files);
+
+    for rel in &src_files {
+        let from = src.join(rel);
+        let to = target.join(rel);
+        let original = fs::read(&from).map_err(|e| CommandError::io("读取文件", &from, e))?;
+        let missing = fs::symlink_metadata(&to).is_err();
+        if !missing && fs::read(&to).is_ok_and(|current| current == original) {
+            continue;
+        }
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent).map_err(|e| CommandError::io("创建目录", parent, e))?;
+        }
+        // 本地为符号链接时先删除，避免写穿到链接指向的位置
+        if fs::symlink_metadata(&to).is_ok_and(|m| m.file_type().is_symlink()) {
+            let _ = fs::remove_file(&to);
+        }
+        fs::write(&to, &original).map_err(|e| CommandError::io("写入文件", &to, e))?;
+        if missing {
+            report.restored.push(rel_string(rel));
+        } else {
+            report.replaced.push(rel_string(rel));
+        }
+    }
+
+    let mut local_files = vec![];
+    collect_files(target, target, &mut local_files);
+    report.extra = local_files
+        .iter()
+        .filter(|rel| !src.join(rel).exists())
+        .map(|rel| rel_string(rel))
+        .collect();
+    report.restored.sort();
+    report.replaced.sort();
+    report.extra.sort();
+    Ok(())
+}
+
+/// 在仓库中查找 skill 目录时的最大深度
+const MAX_LOCATE_DEPTH: usize = 4;
+
+fn is_skill_dir(dir: &Path) -> bool {
+    dir.join("SKILL.md").is_file()
+}
+
+/// 与 npx skills add 一致地定位仓库中的 skill 目录：<id>、skills/<id>、任意层级下名为 <id> 的目录，
+/// 最后是 frontmatter name 为 <id> 的仓库根目录；均含 SKILL.md。返回相对仓库根目录的路径（根目录为空串）
+fn locate_skill_dir(repo_root: &Path, id: &str) -> Option<String> {
+    for rel in [id.to_string(), format!("skills/{id}")] {
+        if is_skill_dir(&repo_root.join(&rel)) {
+            return Some(rel);
+        }
+    }
+    let mut level = vec![repo_root.to_path_buf()];
+    for _ in 0..MAX_LOCATE_DEPTH {
+        let mut next = vec![];
+        for dir in &level {
+            let Ok(rd) = fs::read_dir(dir) else {
+                continue;
+            };
+            let mut children: Vec<PathBuf> = rd
+                .flatten()
+                .filter(|e| e.file_name() != ".git" && e.file_type().is_ok_and(|t| t.is_dir()))
+                .map(|e| e.path())
+                .collect();
+            children.sort();
+            if let Some(found) = children.iter().find(|c| c.file_name().is_some_and(|n| n == id) && is_skill_dir(c)) {
+                return found.strip_prefix(repo_root).ok().map(rel_string);
+            }
+            next.extend(children);
+        }
+        level = next;
+    }
+    let root_name = fs::read_to_string(repo_root.join("SKILL.md")).ok().and_then(|md| parse_skill_md(&md).str_field("name"));
+    (root_name.as_deref() == Some(id)).then(String::new)
+}
+
+/// 按安装记录重新拉取来源并还原缺失 / 被改动的文件；本地编辑过的 skill 需 force 才会覆盖。
+/// 未记录 commit（如 npx 安装）时只能取来源最新版本，属于升级而非还原，同样需要 force
+fn repair_one(install_path: &Path, force: bool) -> CommandResult<RepairReport> {
+    if !install_path.is_dir() {
+        return Err(CommandError::not_found(install_path));
+    }
+    if !is_valid_skills_path(install_path) {
+        return Err(CommandError::PathNotAllowed { path: install_path.display().to_string() });
+    }
+    let mut record = read_record(install_path)
+        .ok_or_else(|| CommandError::invalid("install_path", format!("缺少安装记录 {RECORD_FILE}，无法确定来源")))?;
+    if let Some(modified_at) = record.local_modified_at.filter(|_| !force) {
+        return Err(CommandError::LocallyModified { path: install_path.display().to_string(), modified_at });
+    }
+    if record.commit.is_none() && !force {
+        return Err(CommandError::invalid(
+            "force",
+            "安装记录中没有 commit，无法还原安装时的版本；确认更新到来源最新版本需 force=true",
+        ));
+    }
+    let url = normalize_repo_url(&record.repo)?;
+
+    let tmp = unique_temp_dir(CLONE_TEMP_PREFIX);
+    remove_dir_if_exists(&tmp);
+    let mut attempts: Vec<AttemptRecord> = vec![];
+
+    let result = (|| {
+        let sub_path = record.sub_path.as_deref().filter(|s| !s.trim().is_empty());
+        let staged = stage_repo(&url, sub_path, record.commit.as_deref(), &tmp, &mut attempts)?;
+        // git 完整 clone 安装的 skill 即仓库根目录；npx 安装未记录子目录时拉取的是整个仓库，
+        // 需定位其中的 skill 目录，不能把整个仓库还原进来
+        let (src, resolved) = match sub_path {
+            Some(_) => (staged, None),
+            None if record.method != "npx" => (staged, None),
+            None => {
+                let rel = locate_skill_dir(&tmp, &record.id).ok_or_else(|| {
+                    CommandError::invalid("sub_path", format!("无法在仓库中定位 skill「{}」的目录（需含 SKILL.md）", record.id))
+                })?;
+                (tmp.join(&rel), Some(rel))
+            }
+        };
+        if record.commit.is_none() {
+            // 未记录 commit（如 npx 安装）时取的是最新版本，需重新通过安装检查
+            check_staged_tree(&src, &InstallLimits::default())?;
+            enforce_policy(&scan_dir(&src), &ScanPolicy::default())?;
+        }
+        let commit = git_output(&["rev-parse", "HEAD"], Some(&tmp)).ok();
+        let mut report = RepairReport {
+            id: record.id.clone(),
+            install_path: install_path.display().to_string(),
+            commit: commit.clone(),
+            restored: vec![],
+            replaced: vec![],
+            extra: vec![],
+            upgraded: record.commit.is_none(),
+        };
+        restore_tree(&src, install_path, &mut report)?;
+        Ok((report, resolved))
+    })();
+
+    remove_dir_if_exists(&tmp);
+    let (report, resolved) = result?;
+
+    // 升级后记下实际版本与子目录，之后的修复按此还原；已恢复为来源版本，清除本地修改标记
+    if report.upgraded || record.local_modified_at.is_some() {
+        if report.upgraded {
+            record.commit = report.commit.clone();
+        }
+        if let Some(rel) = resolved.filter(|r| !r.is_empty()) {
+            record.sub_path = Some(rel);
+        }
+        record.local_modified_at = None;
+        write_record(install_path, &record)?;
+    }
+    Ok(report)
+}
+
+/// 修复单个 skill：按 .skillhub.json 中记录的 repo / sub_path / commit 重新拉取，恢复缺失或被改动的文件。
+/// 在 SkillHub 内编辑过的 skill 默认拒绝覆盖（LocallyModified），force=true 时放弃本地修改；
+/// 未记录 commit 的 skill 需 force=true 才会更新到来源最新版本（报告中 upgraded=true）。
+#[tauri::command]
+pub async fn repair_skill(install_path: String, force: Option<bool>) -> CommandResult<RepairReport> {
+    if install_path.trim().is_empty() {
+        return Err(CommandError::invalid("install_path", "不能为空"));
+    }
+    run_blocking(move || repair_one(Path::new(&install_path), force.unwrap_or(false))).await
+}
+
+/// 修复所有平台全局目录下带安装记录的 skill（无记录的手动安装 skill 跳过，本地编辑过的 skill 不覆盖，未记录 commit 的 skill 不升级）
+#[tauri::command]
+pub async fn repair_all_skills() -> CommandResult<Vec<RepairAllEntry>> {
+    run_blocking(|| {
+        let mut out = vec![];
+        for spec in all_platforms() {
+            let Ok(dir) = spec.global_skills_dir() else {
+                continue;
+            };
+            let Ok(rd) = fs::read_dir(&dir) else {
+                continue;
+            };
+            let mut paths: Vec<PathBuf> = rd
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_dir() && p.join(RECORD_FILE).is_file())
+                .collect();
+            paths.sort();
+            for path in paths {
+                let (report, error) = match repair_one(&path, false) {
+                    Ok(r) => (Some(r), None),
+                    Err(e) => (None, Some(e)),
+                };
+                out.push(RepairAllEntry { install_path: path.display().to_string(), report, error });
+            }
+        }
+        Ok(out)
+    })
+    .await
+}
@@ -0,0 +1,425 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::files::skill_root;
+use crate::commands::fs::{skills_dir_for, skills_dir_for_project};
+use crate::commands::git::{ensure_dir, remove_dir_if_exists};
+use crate::commands::record::{now_secs, read_record, write_record, RECORD_FILE};
+use crate::commands::settings::skillhub_dir;
+
+/// 复制方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReplicateMode {
+    /// 独立副本
+    Copy,
+    /// 符号链接到来源目录（各平台共用同一份内容与安装记录）
+    Link,
+    /// 副本，来源变化后自动同步
+    Mirror,
+}
+
+/// 目标平台目录：project_root 为空表示全局
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplicateTarget {
+    pub platform: String,
+    #[serde(default)]
+    pub project_root: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReplicateOutcome {
+    pub platform: String,
+    pub project_root: Option<String>,
+    pub path: Option<String>,
+    pub error: Option<CommandError>,
+}
+
+/// 镜像目标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MirrorTarget {
+    pub platform: String,
+    pub project_root: Option<String>,
+    pub path: String,
+}
+
+/// 镜像关系（持久化到 ~/.skillhub/mirrors.json）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillMirror {
+    /// 来源 skill 目录（规范化路径）
+    pub source: String,
+    pub targets: Vec<MirrorTarget>,
+    pub created_at: u64,
+    #[serde(default)]
+    pub last_synced_at: Option<u64>,
+}
+
+/// 单个镜像目标的同步结果
+#[derive(Debug, Serialize)]
+pub struct MirrorSyncEntry {
+    pub source: String,
+    pub path: String,
+    /// 有文件被更新或删除
+    pub changed: bool,
+    /// 目标在 SkillHub 内被单独编辑过，为保留修改未同步（error 为 LocallyModified）
+    pub conflict: bool,
+    pub error: Option<CommandError>,
+}
+
+fn mirrors_path() -> CommandResult<PathBuf> {
+    Ok(skillhub_dir()?.join("mirrors.json"))
+}
+
+fn load_mirrors() -> Vec<SkillMirror> {
+    mirrors_path()
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn write_mirrors(mirrors: &[SkillMirror]) -> CommandResult<()> {
+    let path = mirrors_path()?;
+    if let Some(parent) = path.parent() {
+        ensure_dir(parent)?;
+    }
+    let text = serde_json::to_string_pretty(mirrors)
+        .map_err(|e| CommandError::Serialization { reason: e.to_string() })?;
+    fs::write(&path, text).map_err(|e| CommandError::io("写入镜像列表", &path, e))
+}
+
+/// 收集目录下的文件与子目录（相对路径；忽略 .git 与安装记录，不跟随符号链接目录）
+fn collect_tree(root: &Path, dir: &Path, files: &mut BTreeSet<PathBuf>, dirs: &mut BTreeSet<PathBuf>) {
+    let Ok(rd) = fs::read_dir(dir) else {
+        return;
+    };
+    for ent in rd.flatten() {
+        let name = ent.file_name();
+        if name == ".git" || (dir == root && name == RECORD_FILE) {
+            continue;
+        }
+        let path = ent.path();
+        let Ok(meta) = fs::symlink_metadata(&path) else {
+            continue;
+        };
+        let Ok(rel) = path.strip_prefix(root).map(Path::to_path_buf) else {
+            continue;
+        };
+        if meta.is_dir() {
+            dirs.insert(rel);
+            collect_tree(root, &path, files, dirs);
+        } else if meta.file_type().is_symlink() && path.is_dir() {
+            continue;
+        } else {
+            files.insert(rel);
+        }
+    }
+}
+
+/// 使 dst 与 src 内容一致：复制新增或内容不同的文件，删除多余文件；只写有差异的文件，避免无谓的文件事件
+fn sync_tree(src: &Path, dst: &Path) -> CommandResult<bool> {
+    let (mut src_files, mut src_dirs) = (BTreeSet::new(), BTreeSet::new());
+    collect_tree(src, src, &mut src_files, &mut src_dirs);
+    let (mut dst_files, mut dst_dirs) = (BTreeSet::new(), BTreeSet::new());
+    collect_tree(dst, dst, &mut dst_files, &mut dst_dirs);
+
+    let mut changed = false;
+    ensure_dir(dst)?;
+    for rel in &src_dirs {
+        ensure_dir(&dst.join(rel))?;
+    }
+    for rel in &src_files {
+        let from = src.join(rel);
+        let to = dst.join(rel);
+        let content = fs::read(&from).map_err(|e| CommandError::io("读取文件", &from, e))?;
+        if fs::read(&to).is_ok_and(|current| current == content) {
+            continue;
+        }
+        // 目标为符号链接时先删除，避免写穿到链接指向的位置
+        if fs::symlink_metadata(&to).is_ok_and(|m| m.file_type().is_symlink()) {
+            let _ = fs::remove_file(&to);
+        }
+        fs::copy(&from, &to).map_err(|e| CommandError::io("复制文件", &from, e))?;
+        changed = true;
+    }
+    for rel in dst_files.difference(&src_files) {
+        let path = dst.join(rel);
+        fs::remove_file(&path).map_err(|e| CommandError::io("删除文件", &path, e))?;
+        changed = true;
+    }
+    // 多余目录整体删除（其子目录若已随父目录删除，再次删除无副作用）
+    for rel in dst_dirs.difference(&src_dirs) {
+        remove_dir_if_exists(&dst.join(rel));
+        changed = true;
+    }
+    Ok(changed)
+}
+
+/// 副本的安装记录：沿用来源记录（包括本地修改标记），平台与项目改为目标；内容未变时不重写
+fn write_target_record(src: &Path, dst: &Path, platform: &str, project_root: Option<&str>) -> CommandResult<()> {
+    let Some(mut record) = read_record(src) else {
+        return Ok(());
+    };
+    record.platform = platform.to_string();
+    record.project_root = project_root.map(str::to_string);
+    let unchanged = read_record(dst)
+        .is_some_and(|current| serde_json::to_value(&current).ok() == serde_json::to_value(&record).ok());
+    if unchanged {
+        return Ok(());
+    }
+    write_record(dst, &record)
+}
+
+/// 目标自身的本地修改时间：目标记录带有与来源不同的 local_modified_at，说明是在目标上单独编辑的
+fn target_modified_at(source: &Path, target: &Path) -> Option<u64> {
+    let modified_at = read_record(target)?.local_modified_at?;
+    let inherited = read_record(source).and_then(|r| r.local_modified_at);
+    (inherited != Some(modified_at)).then_some(modified_at)
+}
+
+#[cfg(unix)]
+fn symlink_dir(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(src, dst)
+}
+
+#[cfg(windows)]
+fn symlink_dir(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::os::windows::fs::symlink_dir(src, dst)
+}
+
+fn target_dir(target: &ReplicateTarget) -> CommandResult<PathBuf> {
+    match target.project_root.as_deref() {
+        Some(root) => skills_dir_for_project(&target.platform, Path::new(root)),
+        None => skills_dir_for(&target.platform),
+    }
+}
+
+fn replicate_one(source: &Path, id: &str, target: &ReplicateTarget, mode: ReplicateMode) -> CommandResult<PathBuf> {
+    let dir = target_dir(target)?;
+    let dest = dir.join(id);
+    if dest.canonicalize().is_ok_and(|d| d == source) {
+        return Err(CommandError::invalid("targets", "目标与来源是同一目录"));
+    }
+    if fs::symlink_metadata(&dest).is_ok() {
+        return Err(CommandError::AlreadyInstalled { path: dest.display().to_string() });
+    }
+    ensure_dir(&dir)?;
+
+    if mode == ReplicateMode::Link {
+        symlink_dir(source, &dest).map_err(|e| CommandError::io("创建链接", &dest, e))?;
+        return Ok(dest);
+    }
+    let result = sync_tree(source, &dest)
+        .and_then(|_| write_target_record(source, &dest, &target.platform, target.project_root.as_deref()));
+    if let Err(e) = result {
+        remove_dir_if_exists(&dest);
+        return Err(e);
+    }
+    Ok(dest.canonicalize().unwrap_or(dest))
+}
+
+fn sync_mirror(mirror: &SkillMirror) -> Vec<MirrorSyncEntry> {
+    let source = Path::new(&mirror.source);
+    mirror
+        .targets
+        .iter()
+        // 来源或目标已被删除 / 停用时跳过，不重新创建
+        .filter(|t| source.is_dir() && Path::new(&t.path).is_dir())
+        .map(|t| {
+            let target = Path::new(&t.path);
+            let entry = |changed, conflict, error| MirrorSyncEntry {
+                source: mirror.source.clone(),
+                path: t.path.clone(),
+                changed,
+                conflict,
+                error,
+            };
+            // 目标被单独编辑过时不覆盖，报告冲突由用户处理（取消镜像保留修改，或修复 / 删除后重新镜像）
+            if let Some(modified_at) = target_modified_at(source, target) {
+                return entry(false, true, Some(CommandError::LocallyModified { path: t.path.clone(), modified_at }));
+            }
+            let synced = sync_tree(source, target)
+                .and_then(|changed| write_target_record(source, target, &t.platform, t.project_root.as_deref()).map(|_| changed));
+            match synced {
+                Ok(changed) => entry(changed, false, None),
+                Err(e) => entry(false, false, Some(e)),
+            }
+        })
+        .collect()
+}
+
+/// 同步来源位于给定 skill 目录的镜像（目录监听在来源变化后调用）
+pub(crate) fn sync_mirrors_for(skill_paths: &[PathBuf]) -> Vec<MirrorSyncEntry> {
+    let sources: Vec<String> = skill_paths
+        .iter()
+        .filter_map(|p| p.canonicalize().ok())
+        .map(|p| p.display().to_string())
+        .collect();
+    let mut mirrors = load_mirrors();
+    let mut out = vec![];
+    for mirror in mirrors.iter_mut().filter(|m| sources.contains(&m.source)) {
+        out.extend(sync_mirror(mirror));
+        mirror.last_synced_at = Some(now_secs());
+    }
+    if !out.is_empty() {
+        let _ = write_mirrors(&mirrors);
+    }
+    out
+}
+
+/// 将已安装的 skill 复制或链接到其他平台的全局 / 项目目录（本地操作，不重新下载）。
+/// mode=mirror 时登记镜像关系，来源目录变化后自动同步到各目标。
+#[tauri::command]
+pub fn replicate_skill(
+    source_path: String,
+    targets: Vec<ReplicateTarget>,
+    mode: Option<ReplicateMode>,
+) -> CommandResult<Vec<ReplicateOutcome>> {
+    let source = skill_root(&source_path)?;
+    let source_key = source.display().to_string();
+    let mode = mode.unwrap_or(ReplicateMode::Copy);
+    let id = source.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let mut mirrors = load_mirrors();
+    // 镜像目标本身不能再作为镜像来源，避免循环同步
+    if mode == ReplicateMode::Mirror && mirrors.iter().flat_map(|m| &m.targets).any(|t| t.path == source_key) {
+        return Err(CommandError::invalid("source_path", "该 skill 是其他 skill 的镜像，不能作为镜像来源"));
+    }
+
+    let mut outcomes = vec![];
+    let mut mirrored = vec![];
+    for mut target in targets {
+        target.platform = target.platform.trim().to_string();
+        target.project_root = target.project_root.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+        let (path, error) = match replicate_one(&source, &id, &target, mode) {
+            Ok(path) => (Some(path.display().to_string()), None),
+            Err(e) => (None, Some(e)),
+        };
+        if let (ReplicateMode::Mirror, Some(path)) = (mode, &path) {
+            mirrored.push(MirrorTarget {
+                platform: target.platform.clone(),
+                project_root: target.project_root.clone(),
+                path: path.clone(),
+            });
+        }
+        outcomes.push(ReplicateOutcome { platform: target.platform, project_root: target.project_root, path, error });
+    }
+
+    if !mirrored.is_empty() {
+        match mirrors.iter_mut().find(|m| m.source == source_key) {
+            Some(existing) => existing.targets.extend(mirrored),
+            None => mirrors.push(SkillMirror {
+                source: source_key,
+                targets: mirrored,
+                created_at: now_secs(),
+                last_synced_at: Some(now_secs()),
+            }),
+        }
+        write_mirrors(&mirrors)?;
+    }
+    Ok(outcomes)
+}
+
+/// 获取所有镜像关系
+#[tauri::command]
+pub fn get_skill_mirrors() -> CommandResult<Vec<SkillMirror>> {
+    Ok(load_mirrors())
+}
+
+/// 立即同步全部镜像（应用启动时也会执行一次，补上未运行期间的变化）
+#[tauri::command]
+pub fn sync_skill_mirrors() -> CommandResult<Vec<MirrorSyncEntry>> {
+    let mut mirrors = load_mirrors();
+    let mut out = vec![];
+    for mirror in &mut mirrors {
+        out.extend(sync_mirror(mirror));
+        mirror.last_synced_at = Some(now_secs());
+    }
+    if !mirrors.is_empty() {
+        write_mirrors(&mirrors)?;
+    }
+    Ok(out)
+}
+
+/// 取消镜像：target_path 为空时取消该来源的全部目标。已复制的目录保留为独立副本。
+#[tauri::command]
+pub fn remove_skill_mirror(source: String, target_path: Option<String>) -> CommandResult<()> {
+    let mut mirrors = load_mirrors();
+    let Some(mirror) = mirrors.iter_mut().find(|m| m.source == source) else {
+        return Err(CommandError::invalid("source", format!("{source} 没有镜像")));
+    };
+    match target_path {
+        Some(path) => mirror.targets.retain(|t| t.path != path),
+        None => mirror.targets.clear(),
+    }
+    mirrors.retain(|m| !m.targets.is_empty());
+    write_mirrors(&mirrors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::record::InstallRecord;
+
+    fn with_record(dir: &Path, local_modified_at: Option<u64>) {
+        fs::create_dir_all(dir).unwrap();
+        let record = InstallRecord { id: "demo".into(), local_modified_at, ..Default::default() };
+        write_record(dir, &record).unwrap();
+    }
+
+    #[test]
+    fn target_edit_is_a_conflict() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (source, target) = (tmp.path().join("source"), tmp.path().join("target"));
+        with_record(&source, None);
+        with_record(&target, Some(100));
+        assert_eq!(target_modified_at(&source, &target), Some(100));
+    }
+
+    #[test]
+    fn inherited_source_edit_is_not_a_conflict() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (source, target) = (tmp.path().join("source"), tmp.path().join("target"));
+        with_record(&source, Some(100));
+        with_record(&target, Some(100));
+        assert_eq!(target_modified_at(&source, &target), None);
+
+        // 目标在继承来源标记之后又被单独编辑
+        with_record(&target, Some(200));
+        assert_eq!(target_modified_at(&source, &target), Some(200));
+    }
+
+    #[test]
+    fn unmodified_or_unrecorded_target_is_not_a_conflict() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (source, target) = (tmp.path().join("source"), tmp.path().join("target"));
+        with_record(&source, Some(100));
+        fs::create_dir_all(&target).unwrap();
+        assert_eq!(target_modified_at(&source, &target), None);
+        with_record(&target, None);
+        assert_eq!(target_modified_at(&source, &target), None);
+    }
+
+    #[test]
+    fn sync_tree_copies_changes_and_removes_extras() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (src, dst) = (tmp.path().join("src"), tmp.path().join("dst"));
+        fs::create_dir_all(src.join("references")).unwrap();
+        fs::write(src.join("SKILL.md"), "v1").unwrap();
+        fs::write(src.join("references/a.md"), "a").unwrap();
+        assert!(sync_tree(&src, &dst).unwrap());
+        assert!(!sync_tree(&src, &dst).unwrap());
+
+        fs::write(src.join("SKILL.md"), "v2").unwrap();
+        fs::remove_dir_all(src.join("references")).unwrap();
+        fs::write(dst.join(RECORD_FILE), "{}").unwrap();
+        assert!(sync_tree(&src, &dst).unwrap());
+        assert_eq!(fs::read_to_string(dst.join("SKILL.md")).unwrap(), "v2");
+        assert!(!dst.join("references").exists());
+        // 目标自己的安装记录不随来源同步删除
+        assert!(dst.join(RECORD_FILE).exists());
+    }
+}
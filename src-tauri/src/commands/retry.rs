@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::commands::error::{CommandError, CommandResult};
+
+/// 单个步骤的最大尝试次数
+const MAX_ATTEMPTS: u32 = 3;
+/// 首次重试前的等待时间，之后每次翻倍
+const BASE_BACKOFF: Duration = Duration::from_secs(1);
+
+/// git / npx 失败分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailureKind {
+    /// 网络抖动等可重试错误
+    Transient,
+    /// 认证失败、仓库不存在等重试无意义的错误
+    Permanent,
+}
+
+/// 一次失败的尝试
+#[derive(Debug, Clone, Serialize)]
+pub struct AttemptRecord {
+    /// 步骤名，如 git clone / npx skills add
+    pub step: String,
+    /// 第几次尝试（从 1 开始）
+    pub attempt: u32,
+    pub kind: FailureKind,
+    pub error: String,
+}
+
+const PERMANENT_PATTERNS: &[&str] = &[
+    "authentication failed",
+    "could not read username",
+    "could not read password",
+    "permission denied",
+    "repository not found",
+    "does not appear to be a git repository",
+    "couldn't find remote ref",
+    "remote branch",
+    "did not match any file",
+    "invalid refspec",
+    "returned error: 401",
+    "returned error: 403",
+    "returned error: 404",
+    "e404",
+];
+
+const TRANSIENT_PATTERNS: &[&str] = &[
+    "could not resolve host",
+    "temporary failure in name resolution",
+    "name or service not known",
+    "eai_again",
+    "enotfound",
+    "connection reset",
+    "econnreset",
+    "connection refused",
+    "econnrefused",
+    "timed out",
+    "etimedout",
+    "early eof",
+    "unexpected disconnect",
+    "the remote end hung up unexpectedly",
+    "rpc failed",
+    "returned error: 5",
+    "http/2 stream",
+    "gnutls_handshake",
+    "ssl_read",
+    "ssl_error_syscall",
+    "curl 18",
+    "curl 56",
+    "socket hang up",
+];
+
+/// 根据外部命令的 stderr 判断失败类型；其余错误及无法识别的输出按不可重试处理
+pub(crate) fn classify_failure(err: &CommandError) -> FailureKind {
+    let CommandError::CommandFailed { stderr_tail, .. } = err else {
+        return FailureKind::Permanent;
+    };
+    let lower = stderr_tail.to_lowercase();
+    if PERMANENT_PATTERNS.iter().any(|p| lower.contains(p)) {
+        return FailureKind::Permanent;
+    }
+    if TRANSIENT_PATTERNS.iter().any(|p| lower.contains(p)) {
+        return FailureKind::Transient;
+    }
+    FailureKind::Permanent
+}
+
+/// 执行 op，遇到可重试错误时先调用 cleanup 清理残留，再按指数退避重试；
+/// 每次失败都追加到 history，最终失败时将完整记录附加到 CommandFailed 上
+pub(crate) fn with_retry<T>(
+    step: &str,
+    history: &mut Vec<AttemptRecord>,
+    mut cleanup: impl FnMut(),
+    mut op: impl FnMut() -> CommandResult<T>,
+) -> CommandResult<T> {
+    let mut attempt = 1;
+    loop {
+        let mut err = match op() {
+            Ok(v) => return Ok(v),
+            Err(e) => e,
+        };
+        let kind = classify_failure(&err);
+        history.push(AttemptRecord {
+            step: step.to_string(),
+            attempt,
+            kind,
+            error: err.to_string(),
+        });
+        if kind == FailureKind::Permanent || attempt >= MAX_ATTEMPTS {
+            if let CommandError::CommandFailed { attempts, .. } = &mut err {
+                if history.len() > 1 {
+                    *attempts = history.clone();
+                }
+            }
+            return Err(err);
+        }
+        cleanup();
+        std::thread::sleep(BASE_BACKOFF * 2u32.pow(attempt - 1));
+        attempt += 1;
+    }
+}
+
+/// 在阻塞线程池中执行同步逻辑（git / npx 子进程与 with_retry 的退避等待），避免占用异步运行时的工作线程
+pub(crate) async fn run_blocking<T: Send + 'static>(
+    f: impl FnOnce() -> CommandResult<T> + Send + 'static,
+) -> CommandResult<T> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| CommandError::Io { operation: "执行后台任务".into(), path: String::new(), reason: e.to_string() })?
+}
+
+/// 将重试记录格式化为附加在错误信息后的说明（仅一次尝试时为空）
+pub(crate) fn format_history(history: &[AttemptRecord]) -> String {
+    if history.len() <= 1 {
+        return String::new();
+    }
+    let lines: Vec<String> = history
+        .iter()
+        .map(|a| {
+            // stderr 的最后一行通常是最关键的 fatal 信息
+            let last_line = a.error.lines().rev().find(|l| !l.trim().is_empty()).unwrap_or_default();
+            format!("- {} 第 {} 次（{:?}）: {}", a.step, a.attempt, a.kind, last_line.trim())
+        })
+        .collect();
+    format!("\n尝试记录:\n{}", lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed(stderr: &str) -> CommandError {
+        CommandError::command_failed("git clone".into(), Some(128), "", stderr)
+    }
+
+    #[test]
+    fn network_errors_are_transient() {
+        for stderr in [
+            "fatal: unable to access 'https://github.com/a/b/': Could not resolve host: github.com",
+            "error: RPC failed; curl 56 GnuTLS recv error",
+            "fatal: the remote end hung up unexpectedly",
+            "npm ERR! code ETIMEDOUT",
+        ] {
+            assert_eq!(classify_failure(&failed(stderr)), FailureKind::Transient, "{stderr}");
+        }
+    }
+
+    #[test]
+    fn auth_and_missing_repo_are_permanent() {
+        for stderr in [
+            "remote: Repository not found.\nfatal: repository 'https://github.com/a/b/' not found",
+            "fatal: Authentication failed for 'https://github.com/a/b/'",
+            "npm ERR! code E404",
+        ] {
+            assert_eq!(classify_failure(&failed(stderr)), FailureKind::Permanent, "{stderr}");
+        }
+    }
+
+    #[test]
+    fn permanent_patterns_win_over_transient() {
+        let stderr = "fatal: Authentication failed\nfatal: the remote end hung up unexpectedly";
+        assert_eq!(classify_failure(&failed(stderr)), FailureKind::Permanent);
+    }
+
+    #[test]
+    fn unknown_output_and_other_errors_are_permanent() {
+        assert_eq!(classify_failure(&failed("something odd")), FailureKind::Permanent);
+        assert_eq!(classify_failure(&CommandError::GitMissing), FailureKind::Permanent);
+    }
+}
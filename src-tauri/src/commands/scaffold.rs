@@ -0,0 +1,309 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value as YamlValue};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::frontmatter::parse_skill_md;
+use crate::commands::fs::{skills_dir_for, skills_dir_for_project};
+use crate::commands::git::{copy_dir_all, ensure_dir, remove_dir_if_exists, validate_skill_id};
+use crate::commands::lint::MAX_DESCRIPTION_CHARS;
+use crate::commands::settings::skillhub_dir;
+
+/// 内置模板：(相对路径, 内容)；scripts/ 下的文件创建后设为可执行
+struct BuiltinTemplate {
+    id: &'static str,
+    label: &'static str,
+    files: &'static [(&'static str, &'static str)],
+}
+
+const MINIMAL_SKILL_MD: &str = r#"---
+name: "{{name}}"
+description: "{{description}}"
+---
+
+# {{name}}
+
+## 何时使用
+
+{{description}}
+
+## 步骤
+
+1.
+"#;
+
+const SCRIPTS_SKILL_MD: &str = r#"---
+name: "{{name}}"
+description: "{{description}}"
+---
+
+# {{name}}
+
+## 何时使用
+
+{{description}}
+
+## 步骤
+
+1. 运行 [scripts/run.sh](scripts/run.sh)
+"#;
+
+const RUN_SH: &str = r#"#!/usr/bin/env bash
+set -euo pipefail
+
+echo "{{name}}: TODO"
+"#;
+
+const REFERENCES_SKILL_MD: &str = r#"---
+name: "{{name}}"
+description: "{{description}}"
+---
+
+# {{name}}
+
+## 何时使用
+
+{{description}}
+
+## 步骤
+
+1.
+
+## 参考
+
+详细说明见 [references/REFERENCE.md](references/REFERENCE.md)，仅在需要时读取。
+"#;
+
+const REFERENCE_MD: &str = r#"# {{name}} 参考
+
+"#;
+
+const BUILTIN_TEMPLATES: &[BuiltinTemplate] = &[
+    BuiltinTemplate { id: "minimal", label: "最简", files: &[("SKILL.md", MINIMAL_SKILL_MD)] },
+    BuiltinTemplate {
+        id: "with-scripts",
+        label: "含脚本",
+        files: &[("SKILL.md", SCRIPTS_SKILL_MD), ("scripts/run.sh", RUN_SH)],
+    },
+    BuiltinTemplate {
+        id: "with-references",
+        label: "含参考文档",
+        files: &[("SKILL.md", REFERENCES_SKILL_MD), ("references/REFERENCE.md", REFERENCE_MD)],
+    },
+];
+
+/// skill 模板（内置模板，或 ~/.skillhub/templates/<id>/ 下含 SKILL.md 的目录）
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillTemplate {
+    pub id: String,
+    pub label: String,
+    pub builtin: bool,
+    /// 用户模板所在目录
+    pub path: Option<String>,
+}
+
+/// 新建 skill 参数：target_dir 与 platform 二选一；给定 platform 时 project_root 为空表示全局目录
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateSkillRequest {
+    pub name: String,
+    pub description: String,
+    /// 模板 id，默认 minimal
+    #[serde(default)]
+    pub template: Option<String>,
+    #[serde(default)]
+    pub platform: Option<String>,
+    #[serde(default)]
+    pub project_root: Option<String>,
+    /// 独立的工作目录（skill 创建在其下）
+    #[serde(default)]
+    pub target_dir: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedSkill {
+    pub path: String,
+    /// 创建的文件（相对 skill 目录）
+    pub files: Vec<String>,
+}
+
+fn user_templates_dir() -> CommandResult<PathBuf> {
+    Ok(skillhub_dir()?.join("templates"))
+}
+
+fn user_templates() -> Vec<SkillTemplate> {
+    let Ok(rd) = user_templates_dir().and_then(|d| fs::read_dir(&d).map_err(|e| CommandError::io("读取目录", &d, e)))
+    else {
+        return vec![];
+    };
+    let mut out: Vec<SkillTemplate> = rd
+        .flatten()
+        .filter(|e| e.path().join("SKILL.md").is_file())
+        .map(|e| {
+            let id = e.file_name().to_string_lossy().to_string();
+            SkillTemplate { label: id.clone(), id, builtin: false, path: Some(e.path().display().to_string()) }
+        })
+        .collect();
+    out.sort_by(|a, b| a.id.cmp(&b.id));
+    out
+}
+
+fn fill(text: &str, name: &str, description: &str) -> String {
+    text.replace("{{name}}", name).replace("{{description}}", description)
+}
+
+/// 生成 SKILL.md：保留模板 frontmatter 的其余字段，name / description 置于最前并以 YAML 序列化（自动处理引号与换行）
+fn render_skill_md(template: &str, name: &str, description: &str) -> CommandResult<String> {
+    let parsed = parse_skill_md(template);
+    let original: Mapping = match parsed.yaml.as_deref().filter(|y| !y.trim().is_empty()) {
+        Some(yaml) => serde_yaml::from_str(yaml)
+            .map_err(|e| CommandError::invalid("template", format!("模板 SKILL.md frontmatter 无效: {e}")))?,
+        None => Mapping::new(),
+    };
+    let mut mapping = Mapping::new();
+    mapping.insert("name".into(), name.into());
+    mapping.insert("description".into(), description.into());
+    for (k, v) in original {
+        if k.as_str() != Some("name") && k.as_str() != Some("description") {
+            mapping.insert(k, v);
+        }
+    }
+    let yaml = serde_yaml::to_string(&YamlValue::Mapping(mapping))
+        .map_err(|e| CommandError::Serialization { reason: e.to_string() })?;
+    Ok(format!("---\n{yaml}---\n{}", fill(&parsed.body, name, description)))
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+    let _ = fs::set_permissions(path, fs::Permissions::from_mode(0o755));
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path) {}
+
+fn write_file(path: &Path, content: &str) -> CommandResult<()> {
+    if let Some(parent) = path.parent() {
+        ensure_dir(parent)?;
+    }
+    fs::write(path, content).map_err(|e| CommandError::io("写入文件", path, e))
+}
+
+/// 递归列出目录下的文件（相对路径）
+fn list_files(root: &Path, dir: &Path, out: &mut Vec<String>) {
+    let Ok(rd) = fs::read_dir(dir) else {
+        return;
+    };
+    for ent in rd.flatten() {
+        let path = ent.path();
+        if path.is_dir() {
+            list_files(root, &path, out);
+        } else {
+            out.push(path.strip_prefix(root).unwrap_or(&path).to_string_lossy().replace('\\', "/"));
+        }
+    }
+}
+
+fn create_from_builtin(template: &BuiltinTemplate, dest: &Path, name: &str, description: &str) -> CommandResult<()> {
+    for (rel, content) in template.files {
+        let path = dest.join(rel);
+        let text = if *rel == "SKILL.md" { render_skill_md(content, name, description)? } else { fill(content, name, description) };
+        write_file(&path, &text)?;
+        if rel.starts_with("scripts/") {
+            set_executable(&path);
+        }
+    }
+    Ok(())
+}
+
+/// 复制用户模板目录（不含 .git），文本文件中的 {{name}} / {{description}} 被替换
+fn create_from_dir(template_dir: &Path, dest: &Path, name: &str, description: &str) -> CommandResult<()> {
+    copy_dir_all(template_dir, dest)?;
+    remove_dir_if_exists(&dest.join(".git"));
+    let mut files = vec![];
+    list_files(dest, dest, &mut files);
+    for rel in files {
+        let path = dest.join(&rel);
+        let Ok(text) = fs::read_to_string(&path) else {
+            continue; // 二进制文件原样保留
+        };
+        let rendered = if rel == "SKILL.md" { render_skill_md(&text, name, description)? } else { fill(&text, name, description) };
+        if rendered != text {
+            write_file(&path, &rendered)?;
+        }
+    }
+    Ok(())
+}
+
+fn target_parent(request: &CreateSkillRequest) -> CommandResult<PathBuf> {
+    let non_empty = |v: &Option<String>| v.as_deref().map(str::trim).filter(|s| !s.is_empty()).map(str::to_string);
+    if let Some(dir) = non_empty(&request.target_dir) {
+        let dir = PathBuf::from(dir);
+        if !dir.is_absolute() {
+            return Err(CommandError::invalid("target_dir", "必须是绝对路径"));
+        }
+        return Ok(dir);
+    }
+    let platform = non_empty(&request.platform)
+        .ok_or_else(|| CommandError::invalid("platform", "需指定平台或目标目录"))?;
+    match non_empty(&request.project_root) {
+        Some(root) => skills_dir_for_project(&platform, Path::new(&root)),
+        None => skills_dir_for(&platform),
+    }
+}
+
+/// 获取可用的 skill 模板（内置 + 用户模板；同 id 的用户模板覆盖内置模板）
+#[tauri::command]
+pub fn get_skill_templates() -> CommandResult<Vec<SkillTemplate>> {
+    let user = user_templates();
+    let mut out: Vec<SkillTemplate> = BUILTIN_TEMPLATES
+        .iter()
+        .filter(|b| !user.iter().any(|u| u.id == b.id))
+        .map(|b| SkillTemplate { id: b.id.to_string(), label: b.label.to_string(), builtin: true, path: None })
+        .collect();
+    out.extend(user);
+    Ok(out)
+}
+
+/// 按模板新建 skill 目录，填写 name / description frontmatter
+#[tauri::command]
+pub fn create_skill(request: CreateSkillRequest) -> CommandResult<CreatedSkill> {
+    let name = request.name.trim().to_string();
+    validate_skill_id(&name)?;
+    let description = request.description.trim().to_string();
+    if description.is_empty() {
+        return Err(CommandError::invalid("description", "不能为空"));
+    }
+    if description.chars().count() > MAX_DESCRIPTION_CHARS {
+        return Err(CommandError::invalid("description", format!("不能超过 {MAX_DESCRIPTION_CHARS} 个字符")));
+    }
+    let template_id = request.template.as_deref().map(str::trim).filter(|s| !s.is_empty()).unwrap_or("minimal");
+    let user_template = user_templates().into_iter().find(|t| t.id == template_id).and_then(|t| t.path);
+    let builtin = BUILTIN_TEMPLATES.iter().find(|t| t.id == template_id);
+    if user_template.is_none() && builtin.is_none() {
+        return Err(CommandError::invalid("template", format!("模板 {template_id} 不存在")));
+    }
+
+    let parent = target_parent(&request)?;
+    let dest = parent.join(&name);
+    if dest.exists() {
+        return Err(CommandError::AlreadyInstalled { path: dest.display().to_string() });
+    }
+    ensure_dir(&parent)?;
+
+    let result = match (user_template, builtin) {
+        (Some(dir), _) => create_from_dir(Path::new(&dir), &dest, &name, &description),
+        (None, Some(b)) => create_from_builtin(b, &dest, &name, &description),
+        (None, None) => Ok(()),
+    };
+    if let Err(e) = result {
+        remove_dir_if_exists(&dest);
+        return Err(e);
+    }
+
+    let mut files = vec![];
+    list_files(&dest, &dest, &mut files);
+    files.sort();
+    Ok(CreatedSkill { path: dest.display().to_string(), files })
+}
@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::fs::home_dir;
+
+/// npm 默认 registry，用于匹配 URL 改写规则
+const NPM_REGISTRY: &str = "https://registry.npmjs.org/";
+
+/// 启动时由设置写入进程环境的变量名
+static PROCESS_ENV_KEYS: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+/// 应用设置（持久化到 ~/.skillhub/settings.json）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    pub network: NetworkSettings,
+    /// 平台 id -> 路径覆盖（优先级低于 agent 自身的环境变量）
+    pub platform_paths: BTreeMap<String, PlatformPathOverride>,
+}
+
+/// 单个平台的路径覆盖（绝对路径，支持 ~/ 开头）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlatformPathOverride {
+    /// agent 配置目录（全局 skills 目录随之变化）
+    pub config_dir: Option<String>,
+    /// 全局 skills 目录
+    pub skills_dir: Option<String>,
+}
+
+/// 网络设置：每次调用 git / npx 及后端 HTTP 请求（http_get_text）时按当前设置生效；
+/// 前端直接经 tauri-plugin-http 发出的请求仅在启动时读取代理与 CA
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkSettings {
+    pub http_proxy: Option<String>,
+    pub https_proxy: Option<String>,
+    /// 不走代理的主机列表
+    pub no_proxy: Vec<String>,
+    /// 自定义 CA 证书文件（PEM）
+    pub ca_bundle: Option<String>,
+    /// URL 改写规则，如 https://github.com/ -> https://mirror.example.com/github.com/；
+    /// 作用于 git（含 npx 内部调用的 git）、npx 拉取 npm 包的 registry 与后端 HTTP 请求（skills.sh）
+    pub url_rewrites: Vec<UrlRewrite>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UrlRewrite {
+    pub from: String,
+    pub to: String,
+}
+
+fn non_empty(v: &Option<String>) -> Option<&str> {
+    v.as_deref().map(str::trim).filter(|s| !s.is_empty())
+}
+
+/// 展开 ~/ 前缀
+fn expand_home(p: &str) -> Option<PathBuf> {
+    match p.strip_prefix("~/").or_else(|| p.strip_prefix("~\\")) {
+        Some(rest) => home_dir().ok().map(|h| h.join(rest)),
+        None => Some(PathBuf::from(p)),
+    }
+}
+
+impl PlatformPathOverride {
+    pub(crate) fn config_dir(&self) -> Option<PathBuf> {
+        non_empty(&self.config_dir).and_then(expand_home)
+    }
+
+    pub(crate) fn skills_dir(&self) -> Option<PathBuf> {
+        non_empty(&self.skills_dir).and_then(expand_home)
+    }
+
+    fn validate(&self, platform: &str) -> CommandResult<()> {
+        for dir in [self.config_dir(), self.skills_dir()].into_iter().flatten() {
+            if !dir.is_absolute() {
+                return Err(CommandError::invalid(
+                    "platform_paths",
+                    format!("{platform} 的路径需为绝对路径: {}", dir.display()),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl NetworkSettings {
+    fn validate(&self) -> CommandResult<()> {
+        for proxy in [non_empty(&self.http_proxy), non_empty(&self.https_proxy)].into_iter().flatten() {
+            if !["http://", "https://", "socks5://", "socks5h://"].iter().any(|p| proxy.starts_with(p)) {
+                return Err(CommandError::invalid("proxy", format!("需以 http(s):// 或 socks5:// 开头: {proxy}")));
+            }
+        }
+        if let Some(ca) = non_empty(&self.ca_bundle) {
+            if !Path::new(ca).is_file() {
+                return Err(CommandError::not_found(Path::new(ca)));
+            }
+        }
+        for r in &self.url_rewrites {
+            let (from, to) = (r.from.trim(), r.to.trim());
+            if from.is_empty() || to.is_empty() {
+                return Err(CommandError::invalid("url_rewrites", "的 from/to 不能为空"));
+            }
+            if from.chars().chain(to.chars()).any(|c| c.is_whitespace() || c == '"' || c == '\'') {
+                return Err(CommandError::invalid("url_rewrites", format!("含非法字符: {from} -> {to}")));
+            }
+        }
+        Ok(())
+    }
+
+    /// 代理 / CA 相关环境变量（git、npm、Node 与 reqwest 均识别）
+    fn proxy_env(&self) -> Vec<(&'static str, String)> {
+        let mut env = vec![];
+        if let Some(p) = non_empty(&self.http_proxy) {
+            for k in ["HTTP_PROXY", "http_proxy", "npm_config_proxy"] {
+                env.push((k, p.to_string()));
+            }
+        }
+        if let Some(p) = non_empty(&self.https_proxy).or(non_empty(&self.http_proxy)) {
+            for k in ["HTTPS_PROXY", "https_proxy", "npm_config_https_proxy"] {
+                env.push((k, p.to_string()));
+            }
+        }
+        let no_proxy: Vec<&str> = self.no_proxy.iter().map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+        if !no_proxy.is_empty() {
+            for k in ["NO_PROXY", "no_proxy", "npm_config_noproxy"] {
+                env.push((k, no_proxy.join(",")));
+            }
+        }
+        if let Some(ca) = non_empty(&self.ca_bundle) {
+            for k in ["NODE_EXTRA_CA_CERTS", "npm_config_cafile", "SSL_CERT_FILE"] {
+                env.push((k, ca.to_string()));
+            }
+        }
+        env
+    }
+
+    /// 按改写规则改写 URL：与 git 的 insteadOf 一致，取匹配的最长前缀
+    pub(crate) fn rewrite_url(&self, url: &str) -> String {
+        self.url_rewrites
+            .iter()
+            .map(|r| (r.from.trim(), r.to.trim()))
+            .filter(|(from, _)| !from.is_empty() && url.starts_with(from))
+            .max_by_key(|(from, _)| from.len())
+            .map(|(from, to)| format!("{to}{}", &url[from.len()..]))
+            .unwrap_or_else(|| url.to_string())
+    }
+
+    /// 请求 url 时使用的代理
+    pub(crate) fn proxy_for(&self, url: &str) -> Option<&str> {
+        if url.starts_with("https://") {
+            non_empty(&self.https_proxy).or(non_empty(&self.http_proxy))
+        } else {
+            non_empty(&self.http_proxy)
+        }
+    }
+
+    pub(crate) fn ca_bundle_path(&self) -> Option<PathBuf> {
+        non_empty(&self.ca_bundle).map(PathBuf::from)
+    }
+
+    /// npm registry 命中改写规则时，让 npx 从改写后的地址拉取包
+    fn npm_registry_env(&self) -> Option<(&'static str, String)> {
+        let rewritten = self.rewrite_url(NPM_REGISTRY);
+        (rewritten != NPM_REGISTRY).then_some(("npm_config_registry", rewritten))
+    }
+
+    /// 通过 GIT_CONFIG_COUNT/KEY/VALUE 注入 git 配置，npx 内部调用的 git 同样生效；
+    /// 进程环境中已有的 GIT_CONFIG_* 条目保留，新条目从其后编号
+    fn git_config_env(&self) -> Vec<(String, String)> {
+        let mut pairs: Vec<(String, String)> = vec![];
+        if let Some(p) = non_empty(&self.https_proxy).or(non_empty(&self.http_proxy)) {
+            pairs.push(("http.proxy".into(), p.to_string()));
+        }
+        if let Some(ca) = non_empty(&self.ca_bundle) {
+            pairs.push(("http.sslCAInfo".into(), ca.to_string()));
+        }
+        for r in &self.url_rewrites {
+            pairs.push((format!("url.{}.insteadOf", r.to.trim()), r.from.trim().to_string()));
+        }
+        if pairs.is_empty() {
+            return vec![];
+        }
+        let existing = std::env::var("GIT_CONFIG_COUNT").ok().and_then(|c| c.trim().parse::<usize>().ok()).unwrap_or(0);
+        let mut env = vec![("GIT_CONFIG_COUNT".to_string(), (existing + pairs.len()).to_string())];
+        for (i, (k, v)) in (existing..).zip(pairs) {
+            env.push((format!("GIT_CONFIG_KEY_{i}"), k));
+            env.push((format!("GIT_CONFIG_VALUE_{i}"), v));
+        }
+        env
+    }
+
+    /// 为子进程（git / npx）注入网络设置；启动时写入进程环境、此后已被清除的设置不再传给子进程
+    pub(crate) fn apply_to_command(&self, cmd: &mut Command) {
+        for k in PROCESS_ENV_KEYS.get().into_iter().flatten() {
+            cmd.env_remove(k);
+        }
+        cmd.envs(self.proxy_env());
+        cmd.envs(self.npm_registry_env());
+        cmd.envs(self.git_config_env());
+    }
+
+    /// 写入当前进程环境变量，供 Rust 侧 HTTP 客户端（tauri-plugin-http / reqwest）读取代理与 CA。
+    /// 修改进程环境不是线程安全的，只能在 run() 中创建其他线程之前调用一次；运行中修改的设置在下次启动时生效
+    pub(crate) fn apply_to_process(&self) {
+        let env = self.proxy_env();
+        for (k, v) in &env {
+            std::env::set_var(k, v);
+        }
+        let _ = PROCESS_ENV_KEYS.set(env.into_iter().map(|(k, _)| k).collect());
+    }
+}
+
+/// SkillHub 自身的数据目录 ~/.skillhub
+pub(crate) fn skillhub_dir() -> CommandResult<PathBuf> {
+    Ok(home_dir()?.join(".skillhub"))
+}
+
+fn settings_path() -> CommandResult<PathBuf> {
+    Ok(skillhub_dir()?.join("settings.json"))
+}
+
+/// 读取设置；文件不存在或损坏时返回默认值
+pub(crate) fn load_settings() -> AppSettings {
+    settings_path()
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn write_settings(settings: &AppSettings) -> CommandResult<()> {
+    let path = settings_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| CommandError::io("创建目录", parent, e))?;
+    }
+    let text = serde_json::to_string_pretty(settings)
+        .map_err(|e| CommandError::Serialization { reason: e.to_string() })?;
+    fs::write(&path, text).map_err(|e| CommandError::io("写入设置", &path, e))
+}
+
+/// 获取应用设置
+#[tauri::command]
+pub fn get_settings() -> CommandResult<AppSettings> {
+    Ok(load_settings())
+}
+
+/// 保存应用设置：git / npx 在下次调用时即使用新设置，Rust 侧 HTTP 客户端的代理与 CA 需重启应用后生效
+#[tauri::command]
+pub fn save_settings(settings: AppSettings) -> CommandResult<()> {
+    settings.network.validate()?;
+    for (platform, paths) in &settings.platform_paths {
+        paths.validate(platform)?;
+    }
+    write_settings(&settings)
+}
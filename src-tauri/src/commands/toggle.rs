@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::fs::{read_skill_meta, skills_dir_for, skills_dir_for_project, InstalledSkillMeta, SkillScope};
+use crate::commands::git::{ensure_dir, move_dir, validate_skill_id};
+use crate::commands::record::now_secs;
+use crate::commands::settings::skillhub_dir;
+
+/// 已停用的 skill（记录在 ~/.skillhub/disabled/index.json）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisabledSkill {
+    pub id: String,
+    pub platform: String,
+    pub scope: SkillScope,
+    pub project_root: Option<String>,
+    /// 启用后恢复到的目录
+    pub original_path: String,
+    /// 停用期间所在目录
+    pub disabled_path: String,
+    /// 停用时间（Unix 秒）
+    pub disabled_at: u64,
+}
+
+pub(crate) fn disabled_root() -> CommandResult<PathBuf> {
+    Ok(skillhub_dir()?.join("disabled"))
+}
+
+fn index_path() -> CommandResult<PathBuf> {
+    Ok(disabled_root()?.join("index.json"))
+}
+
+pub(crate) fn load_disabled() -> Vec<DisabledSkill> {
+    index_path()
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn write_disabled(entries: &[DisabledSkill]) -> CommandResult<()> {
+    let path = index_path()?;
+    if let Some(parent) = path.parent() {
+        ensure_dir(parent)?;
+    }
+    let text = serde_json::to_string_pretty(entries)
+        .map_err(|e| CommandError::Serialization { reason: e.to_string() })?;
+    fs::write(&path, text).map_err(|e| CommandError::io("写入停用列表", &path, e))
+}
+
+/// 项目路径的稳定短标识（FNV-1a），用于区分不同项目的停用目录
+pub(crate) fn project_key(root: &Path) -> String {
+    let name = root.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let hash = root
+        .to_string_lossy()
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |h, b| (h ^ b as u64).wrapping_mul(0x100000001b3));
+    format!("{name}-{hash:016x}")
+}
+
+/// 停用区目录：disabled/<platform>/global 或 disabled/<platform>/projects/<项目标识>
+fn disabled_dir_for(platform: &str, project_root: Option<&Path>) -> CommandResult<PathBuf> {
+    let base = disabled_root()?.join(platform);
+    Ok(match project_root {
+        Some(root) => base.join("projects").join(project_key(root)),
+        None => base.join("global"),
+    })
+}
+
+fn matches(entry: &DisabledSkill, platform: &str, skill_id: &str, project_root: Option<&str>) -> bool {
+    entry.id == skill_id && entry.platform == platform && entry.project_root.as_deref() == project_root
+}
+
+/// 停用的 skill 在列表中的元数据（enabled = false）
+pub(crate) fn disabled_skill_metas(platform: &str, project_root: Option<&Path>) -> Vec<InstalledSkillMeta> {
+    let root = project_root.map(|p| p.display().to_string());
+    load_disabled()
+        .into_iter()
+        .filter(|e| e.platform == platform)
+        .filter(|e| e.project_root.is_none() || e.project_root == root)
+        .filter(|e| Path::new(&e.disabled_path).is_dir())
+        .map(|e| {
+            let mut meta = read_skill_meta(e.id, Path::new(&e.disabled_path), e.scope);
+            meta.enabled = false;
+            meta
+        })
+        .collect()
+}
+
+/// 启用 / 停用 skill：停用时移出 agent 可见目录到 SkillHub 管理的停用区（内容与安装记录随目录保留），启用时移回原位置
+#[tauri::command]
+pub fn toggle_skill(
+    platform: String,
+    skill_id: String,
+    enabled: bool,
+    project_root: Option<String>,
+) -> CommandResult<InstalledSkillMeta> {
+    validate_skill_id(&skill_id)?;
+    let platform = platform.trim().to_string();
+    let project_root = project_root.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+    let root = project_root.as_deref().map(Path::new);
+    let (scope, visible_dir) = match root {
+        Some(r) => (SkillScope::Project, skills_dir_for_project(&platform, r)?),
+        None => (SkillScope::Global, skills_dir_for(&platform)?),
+    };
+    let visible_path = visible_dir.join(&skill_id);
+    let mut entries = load_disabled();
+
+    if enabled {
+        let pos = entries
+            .iter()
+            .position(|e| matches(e, &platform, &skill_id, project_root.as_deref()))
+            .ok_or_else(|| CommandError::invalid("skill_id", format!("{skill_id} 未处于停用状态")))?;
+        let disabled_path = PathBuf::from(&entries[pos].disabled_path);
+        if !disabled_path.is_dir() {
+            return Err(CommandError::not_found(&disabled_path));
+        }
+        if visible_path.exists() {
+            return Err(CommandError::AlreadyInstalled { path: visible_path.display().to_string() });
+        }
+        ensure_dir(&visible_dir)?;
+        move_dir(&disabled_path, &visible_path)?;
+        entries.remove(pos);
+        write_disabled(&entries)?;
+        return Ok(read_skill_meta(skill_id, &visible_path, scope));
+    }
+
+    if !visible_path.is_dir() {
+        return Err(CommandError::not_found(&visible_path));
+    }
+    let disabled_dir = disabled_dir_for(&platform, root)?;
+    let disabled_path = disabled_dir.join(&skill_id);
+    if disabled_path.exists() {
+        return Err(CommandError::AlreadyInstalled { path: disabled_path.display().to_string() });
+    }
+    ensure_dir(&disabled_dir)?;
+    move_dir(&visible_path, &disabled_path)?;
+    entries.retain(|e| !matches(e, &platform, &skill_id, project_root.as_deref()));
+    entries.push(DisabledSkill {
+        id: skill_id.clone(),
+        platform,
+        scope,
+        project_root,
+        original_path: visible_path.display().to_string(),
+        disabled_path: disabled_path.display().to_string(),
+        disabled_at: now_secs(),
+    });
+    write_disabled(&entries)?;
+
+    let mut meta = read_skill_meta(skill_id, &disabled_path, scope);
+    meta.enabled = false;
+    Ok(meta)
+}
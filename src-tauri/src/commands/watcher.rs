@@ -0,0 +1,245 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use notify_debouncer_full::notify::{EventKind, RecommendedWatcher, RecursiveMode};
+use notify_debouncer_full::{new_debouncer, DebounceEventResult, Debouncer, RecommendedCache};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::fs::SkillScope;
+use crate::commands::platforms::all_platforms;
+use crate::commands::projects::load_projects;
+use crate::commands::replicate::{sync_mirrors_for, MirrorSyncEntry};
+
+/// 推送给前端的事件名
+pub const SKILLS_CHANGED_EVENT: &str = "skills://changed";
+/// 镜像同步失败或冲突时推送，payload 为出问题的 MirrorSyncEntry 列表
+pub const MIRROR_SYNC_EVENT: &str = "skills://mirror-sync";
+
+/// 合并短时间内的连续文件事件（如 git pull、npx 批量写入）
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// skill 目录变更事件
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillChangeEvent {
+    pub kind: SkillChangeKind,
+    pub platform: String,
+    pub scope: SkillScope,
+    pub project_root: Option<String>,
+    pub skill_id: String,
+    pub path: String,
+}
+
+/// 被监听的 skills 目录
+#[derive(Debug, Clone)]
+struct WatchTarget {
+    platform: String,
+    scope: SkillScope,
+    project_root: Option<String>,
+    dir: PathBuf,
+}
+
+struct WatchState {
+    _debouncer: Debouncer<RecommendedWatcher, RecommendedCache>,
+    targets: Vec<WatchTarget>,
+    /// skills 目录 -> 当前存在的 skill 目录名，用于区分新增 / 删除 / 修改
+    snapshot: HashMap<PathBuf, HashSet<String>>,
+}
+
+static STATE: Mutex<Option<WatchState>> = Mutex::new(None);
+/// 最近一次建立监听失败的原因（成功后清除），由体检报告给出
+static LAST_ERROR: Mutex<Option<String>> = Mutex::new(None);
+
+/// 监听未能建立时的错误信息
+pub(crate) fn watcher_error() -> Option<String> {
+    LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// 各平台全局目录 + 已登记项目的项目级目录（去重）
+fn watch_targets() -> Vec<WatchTarget> {
+    let platforms = all_platforms();
+    let mut seen = HashSet::new();
+    let mut targets = vec![];
+    for spec in &platforms {
+        if let Ok(dir) = spec.global_skills_dir() {
+            if seen.insert(dir.clone()) {
+                targets.push(WatchTarget { platform: spec.id.clone(), scope: SkillScope::Global, project_root: None, dir });
+            }
+        }
+    }
+    for project in load_projects() {
+        for spec in &platforms {
+            if let Ok(dir) = spec.project_skills_dir(Path::new(&project.path)) {
+                if seen.insert(dir.clone()) {
+                    targets.push(WatchTarget {
+                        platform: spec.id.clone(),
+                        scope: SkillScope::Project,
+                        project_root: Some(project.path.clone()),
+                        dir,
+                    });
+                }
+            }
+        }
+    }
+    targets
+}
+
+fn list_skill_names(dir: &Path) -> HashSet<String> {
+    fs::read_dir(dir)
+        .map(|rd| {
+            rd.flatten()
+                .filter(|e| e.path().is_dir())
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 将变更路径归类到具体的 skill，并与快照比较得出事件类型
+fn classify(state: &mut WatchState, paths: &[PathBuf]) -> (Vec<SkillChangeEvent>, bool) {
+    let mut events: Vec<SkillChangeEvent> = vec![];
+    let mut needs_rewatch = false;
+    for path in paths {
+        // 尚不存在的 skills 目录被创建：需要重新建立监听
+        if state.targets.iter().any(|t| !state.snapshot.contains_key(&t.dir) && t.dir.starts_with(path) && t.dir.is_dir()) {
+            needs_rewatch = true;
+        }
+        let Some(target) = state.targets.iter().find(|t| path.starts_with(&t.dir) && path != &t.dir) else {
+            continue;
+        };
+        let Some(id) = path
+            .strip_prefix(&target.dir)
+            .ok()
+            .and_then(|rel| rel.components().next())
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+        else {
+            continue;
+        };
+        if events.iter().any(|e| e.skill_id == id && e.platform == target.platform && e.project_root == target.project_root) {
+            continue;
+        }
+        let skill_path = target.dir.join(&id);
+        let known = state.snapshot.entry(target.dir.clone()).or_default();
+        let kind = match (skill_path.is_dir(), known.contains(&id)) {
+            (true, false) => {
+                known.insert(id.clone());
+                SkillChangeKind::Added
+            }
+            (false, true) => {
+                known.remove(&id);
+                SkillChangeKind::Removed
+            }
+            (true, true) => SkillChangeKind::Modified,
+            (false, false) => continue,
+        };
+        events.push(SkillChangeEvent {
+            kind,
+            platform: target.platform.clone(),
+            scope: target.scope,
+            project_root: target.project_root.clone(),
+            skill_id: id,
+            path: skill_path.display().to_string(),
+        });
+    }
+    (events, needs_rewatch)
+}
+
+fn handle_events(app: &AppHandle, result: DebounceEventResult) {
+    let Ok(events) = result else {
+        return;
+    };
+    // 只关心创建 / 删除 / 修改；访问事件（包括监听器自身遍历目录）忽略
+    let paths: Vec<PathBuf> = events
+        .into_iter()
+        .filter(|e| matches!(e.kind, EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(_)))
+        .flat_map(|e| e.event.paths)
+        .collect();
+    let (changes, needs_rewatch) = {
+        let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+        match guard.as_mut() {
+            Some(state) => classify(state, &paths),
+            None => return,
+        }
+    };
+    // 列表接口每次都直接扫描目录，没有需要失效的后端缓存；前端收到事件后重新拉取
+    if !changes.is_empty() {
+        let _ = app.emit(SKILLS_CHANGED_EVENT, &changes);
+        // 镜像来源变化后同步到各目标；目标的写入会再触发一轮事件，但目标不会是镜像来源，不会循环
+        let changed: Vec<PathBuf> = changes
+            .iter()
+            .filter(|c| c.kind != SkillChangeKind::Removed)
+            .map(|c| PathBuf::from(&c.path))
+            .collect();
+        report_mirror_sync(app, sync_mirrors_for(&changed));
+    }
+    if needs_rewatch {
+        // 不能在 debouncer 自身的回调线程里替换它
+        let app = app.clone();
+        std::thread::spawn(move || {
+            let _ = start_watching(app);
+        });
+    }
+}
+
+/// 将同步失败或冲突的镜像目标推送给前端
+pub(crate) fn report_mirror_sync(app: &AppHandle, entries: Vec<MirrorSyncEntry>) {
+    let failed: Vec<MirrorSyncEntry> = entries.into_iter().filter(|e| e.error.is_some()).collect();
+    if !failed.is_empty() {
+        let _ = app.emit(MIRROR_SYNC_EVENT, &failed);
+    }
+}
+
+/// 建立（或重建）监听：存在的 skills 目录递归监听；不存在的监听其最近的已存在父目录，目录出现后自动重建。
+/// 失败原因会被记录，供体检（run_doctor）展示
+pub(crate) fn start_watching(app: AppHandle) -> CommandResult<Vec<String>> {
+    let result = watch_all(app);
+    *LAST_ERROR.lock().unwrap_or_else(|e| e.into_inner()) = result.as_ref().err().map(|e| e.to_string());
+    result
+}
+
+fn watch_all(app: AppHandle) -> CommandResult<Vec<String>> {
+    let handler_app = app.clone();
+    let mut debouncer = new_debouncer(DEBOUNCE, None, move |res: DebounceEventResult| handle_events(&handler_app, res))
+        .map_err(|e| CommandError::io("创建目录监听", Path::new(""), e))?;
+
+    let targets = watch_targets();
+    let mut snapshot = HashMap::new();
+    let mut watched: Vec<String> = vec![];
+    let mut watched_parents = HashSet::new();
+    for target in &targets {
+        if target.dir.is_dir() {
+            if debouncer.watch(&target.dir, RecursiveMode::Recursive).is_ok() {
+                snapshot.insert(target.dir.clone(), list_skill_names(&target.dir));
+                watched.push(target.dir.display().to_string());
+            }
+            continue;
+        }
+        if let Some(parent) = target.dir.ancestors().skip(1).find(|p| p.is_dir()) {
+            if watched_parents.insert(parent.to_path_buf()) {
+                let _ = debouncer.watch(parent, RecursiveMode::NonRecursive);
+            }
+        }
+    }
+
+    let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    *guard = Some(WatchState { _debouncer: debouncer, targets, snapshot });
+    Ok(watched)
+}
+
+/// 重新建立 skills 目录监听（登记新项目或修改平台路径后调用），返回正在监听的目录
+#[tauri::command]
+pub fn rewatch_skills(app: AppHandle) -> CommandResult<Vec<String>> {
+    start_watching(app)
+}
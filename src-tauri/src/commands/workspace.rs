@@ -0,0 +1,126 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use ignore::WalkBuilder;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::fs::home_dir;
+use crate::commands::platforms::{all_platforms, PlatformSpec};
+use crate::commands::projects::{remember_project, scan_project_skills, ProjectSkill, ProjectSource};
+use crate::commands::retry::run_blocking;
+
+/// 工作区扫描选项
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WorkspaceScanOptions {
+    /// 相对扫描根目录的最大深度
+    pub max_depth: usize,
+    /// 扫描时间上限（秒），超时返回已找到的结果
+    pub timeout_secs: u64,
+    /// 是否将发现的项目登记到已知项目
+    pub register: bool,
+}
+
+impl Default for WorkspaceScanOptions {
+    fn default() -> Self {
+        Self { max_depth: 6, timeout_secs: 20, register: false }
+    }
+}
+
+/// 发现的项目
+#[derive(Debug, Serialize)]
+pub struct DiscoveredProject {
+    pub path: String,
+    pub name: String,
+    /// 存在 skills 目录的平台
+    pub platforms: Vec<String>,
+    pub skills: Vec<ProjectSkill>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WorkspaceScanResult {
+    pub projects: Vec<DiscoveredProject>,
+    pub dirs_visited: u64,
+    /// 因超时提前结束
+    pub timed_out: bool,
+    pub elapsed_ms: u64,
+}
+
+/// 不进入的目录（即使未被 .gitignore 忽略）
+const SKIP_DIRS: &[&str] = &["node_modules", "target", "dist", "build", "vendor", "__pycache__"];
+
+/// 目录下存在项目级 skills 目录的平台
+fn platforms_with_skills_dir(specs: &[PlatformSpec], dir: &Path) -> Vec<String> {
+    specs
+        .iter()
+        .filter(|spec| spec.project_skills_dir(dir).is_ok_and(|d| d.is_dir()))
+        .map(|spec| spec.id.clone())
+        .collect()
+}
+
+/// 扫描目录树，找出包含任一平台项目级 skills 目录的项目（遵循 .gitignore，跳过隐藏目录，受深度与时间限制）
+#[tauri::command]
+pub async fn scan_workspace(root: String, options: Option<WorkspaceScanOptions>) -> CommandResult<WorkspaceScanResult> {
+    run_blocking(move || walk_workspace(&root, options.unwrap_or_default())).await
+}
+
+fn walk_workspace(root: &str, options: WorkspaceScanOptions) -> CommandResult<WorkspaceScanResult> {
+    let root_path = Path::new(root.trim());
+    if !root_path.is_dir() {
+        return Err(CommandError::not_found(root_path));
+    }
+    // 从规范化的根目录遍历且不跟随符号链接，遍历到的路径可直接与规范化的用户目录比较
+    let root_path = root_path.canonicalize().map_err(|e| CommandError::io("解析路径", root_path, e))?;
+    // 用户目录下的 .claude/skills 等是全局目录，不视为项目
+    let home = home_dir().ok().and_then(|h| h.canonicalize().ok());
+    let specs = all_platforms();
+
+    let started = Instant::now();
+    let deadline = Duration::from_secs(options.timeout_secs);
+    let mut result = WorkspaceScanResult { projects: vec![], dirs_visited: 0, timed_out: false, elapsed_ms: 0 };
+
+    let walker = WalkBuilder::new(&root_path)
+        .max_depth(Some(options.max_depth))
+        .hidden(true)
+        .git_ignore(true)
+        .git_exclude(true)
+        .require_git(false)
+        .follow_links(false)
+        .filter_entry(|e| {
+            e.file_type().is_some_and(|t| t.is_dir())
+                && !SKIP_DIRS.iter().any(|s| e.file_name() == *s)
+        })
+        .build();
+
+    for entry in walker {
+        if started.elapsed() > deadline {
+            result.timed_out = true;
+            break;
+        }
+        let Ok(entry) = entry else {
+            continue;
+        };
+        result.dirs_visited += 1;
+        let dir = entry.path();
+        if home.as_deref() == Some(dir) {
+            continue;
+        }
+        let platforms = platforms_with_skills_dir(&specs, dir);
+        if platforms.is_empty() {
+            continue;
+        }
+        if options.register {
+            let _ = remember_project(dir, ProjectSource::Scan);
+        }
+        result.projects.push(DiscoveredProject {
+            path: dir.display().to_string(),
+            name: dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+            platforms,
+            skills: scan_project_skills(dir),
+        });
+    }
+
+    result.elapsed_ms = started.elapsed().as_millis() as u64;
+    Ok(result)
+}
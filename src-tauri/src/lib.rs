@@ -12,6 +12,9 @@ pub fn run() {
         kind: MigrationKind::Up,
     }];
 
+    // 在创建任何线程之前应用持久化的代理 / CA 设置，供 Rust 侧 HTTP 客户端读取；git / npx 每次调用时单独注入
+    commands::settings::load_settings().network.apply_to_process();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_http::init())
@@ -20,16 +23,60 @@ pub fn run() {
                 .add_migrations("sqlite:skills.db", migrations)
                 .build(),
         )
+        .setup(|app| {
+            // 监听各平台 skills 目录，变更通过 skills://changed 事件推送给前端；失败原因由体检报告给出
+            let _ = commands::watcher::start_watching(app.handle().clone());
+            // 补上应用未运行期间镜像来源的变化
+            let handle = app.handle().clone();
+            std::thread::spawn(move || {
+                if let Ok(entries) = commands::replicate::sync_skill_mirrors() {
+                    commands::watcher::report_mirror_sync(&handle, entries);
+                }
+            });
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             commands::greet,
             commands::install_skill,
             commands::install_skill_to_all_platforms,
             commands::fs::get_detected_platforms,
+            commands::detect::detect_platforms,
+            commands::http::http_get_text,
             commands::fs::get_installed_skill_ids_anywhere,
             commands::fs::get_installed_platforms_for_skills,
             commands::fs::uninstall_skill,
             commands::fs::get_installed_skill_ids,
-            commands::fs::get_installed_skills
+            commands::fs::get_installed_skills,
+            commands::toggle::toggle_skill,
+            commands::profiles::get_profiles,
+            commands::profiles::save_profile,
+            commands::profiles::delete_profile,
+            commands::profiles::apply_profile,
+            commands::platforms::get_platforms,
+            commands::platforms::get_platform_paths,
+            commands::doctor::run_doctor,
+            commands::projects::get_known_projects,
+            commands::projects::add_known_project,
+            commands::projects::remove_known_project,
+            commands::projects::get_project_inventory,
+            commands::workspace::scan_workspace,
+            commands::watcher::rewatch_skills,
+            commands::repair::repair_skill,
+            commands::repair::repair_all_skills,
+            commands::scan::scan_skill,
+            commands::lint::lint_skill,
+            commands::files::list_skill_files,
+            commands::files::read_skill_file,
+            commands::edit::write_skill_file,
+            commands::export::export_skill,
+            commands::replicate::replicate_skill,
+            commands::replicate::get_skill_mirrors,
+            commands::replicate::sync_skill_mirrors,
+            commands::replicate::remove_skill_mirror,
+            commands::scaffold::get_skill_templates,
+            commands::scaffold::create_skill,
+            commands::settings::get_settings,
+            commands::settings::save_settings
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1,9 +1,14 @@
+import { useEffect } from 'react';
 import { BrowserRouter, Routes, Route, NavLink, Navigate } from 'react-router-dom';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { toast } from 'sonner';
 import { Discover } from '@/pages/Discover';
 import { MySkills } from '@/pages/MySkills';
 import { Settings } from '@/pages/Settings';
 import { Compass, Package, Settings as SettingsIcon } from 'lucide-react';
 import { cn } from '@/lib/utils';
+import { useStore } from '@/store/useStore';
 
 const navItems = [
   { to: '/', icon: Compass, label: '技能市集' },
@@ -11,7 +16,70 @@ const navItems = [
   { to: '/settings', icon: SettingsIcon, label: '设置中心' },
 ];
 
+interface MirrorSyncEntry {
+  source: string;
+  path: string;
+  conflict: boolean;
+  error?: { message: string } | null;
+}
+
+interface DoctorFinding {
+  check: string;
+  level: 'ok' | 'warning' | 'error';
+  message: string;
+  fix?: string | null;
+}
+
 function AppLayout() {
+  const loadPlatforms = useStore((s) => s.loadPlatforms);
+  const refreshInstalledSkills = useStore((s) => s.refreshInstalledSkills);
+
+  /** 加载平台定义（含用户自定义平台） */
+  useEffect(() => {
+    loadPlatforms().catch(() => {});
+  }, [loadPlatforms]);
+
+  /** skills 目录变更（npx、git pull 或其他工具写入）时自动刷新 */
+  useEffect(() => {
+    if (!('__TAURI__' in window)) return;
+    const unlisten = listen('skills://changed', () => {
+      refreshInstalledSkills().catch(() => {});
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [refreshInstalledSkills]);
+
+  /** 镜像同步失败或目标有本地修改（未被覆盖）时提示 */
+  useEffect(() => {
+    if (!('__TAURI__' in window)) return;
+    const unlisten = listen<MirrorSyncEntry[]>('skills://mirror-sync', ({ payload }) => {
+      payload.forEach((e) =>
+        toast.warning(e.conflict ? `镜像目标有本地修改，已跳过同步: ${e.path}` : `镜像同步失败: ${e.path}`, {
+          description: e.conflict ? '取消该镜像可保留修改，或修复 / 删除后重新镜像' : e.error?.message,
+        }),
+      );
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  /** 启动体检：git 缺失等致命问题直接提示；目录监听未启动时列表不会自动刷新，同样提示 */
+  useEffect(() => {
+    if (!('__TAURI__' in window)) return;
+    invoke<{ findings: DoctorFinding[] }>('run_doctor')
+      .then(({ findings }) => {
+        findings
+          .filter((f) => f.level === 'error')
+          .forEach((f) => toast.error(f.message, { description: f.fix ?? undefined }));
+        findings
+          .filter((f) => f.check === 'skills_watcher' && f.level === 'warning')
+          .forEach((f) => toast.warning(f.message, { description: f.fix ?? undefined }));
+      })
+      .catch(() => {});
+  }, []);
+
   return (
     <div className="flex h-screen bg-background">
       {/* 侧边栏 - 科技感面板 */}
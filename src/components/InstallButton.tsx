@@ -3,7 +3,7 @@ import { Button } from '@/components/ui/button';
 import { Tooltip, TooltipContent, TooltipTrigger } from '@/components/ui/tooltip';
 import type { RegistrySkill } from '@/data/registry';
 import { Download, Check } from 'lucide-react';
-import { useStore, PLATFORMS } from '@/store/useStore';
+import { useStore } from '@/store/useStore';
 import { InstallTargetModal } from './InstallTargetModal';
 
 interface InstallButtonProps {
@@ -21,9 +21,10 @@ interface InstallButtonProps {
 export function InstallButton({ skill, isInstalled = false, installedPlatforms = [] }: InstallButtonProps) {
   const [modalOpen, setModalOpen] = useState(false);
   const refreshInstalledSkills = useStore((s) => s.refreshInstalledSkills);
+  const platforms = useStore((s) => s.platforms);
   const tooltipText =
     installedPlatforms.length > 0
-      ? `已安装于：${installedPlatforms.map((p) => PLATFORMS.find((x) => x.value === p)?.label ?? p).join('、')}`
+      ? `已安装于：${installedPlatforms.map((p) => platforms.find((x) => x.value === p)?.label ?? p).join('、')}`
       : '';
 
   const handleComplete = () => {
@@ -4,16 +4,27 @@ import { open } from '@tauri-apps/plugin-dialog';
 import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter } from '@/components/ui/dialog';
 import { Button } from '@/components/ui/button';
 import { Input } from '@/components/ui/input';
-import { FolderOpen } from 'lucide-react';
+import { FolderOpen, ShieldAlert } from 'lucide-react';
 import { toast } from 'sonner';
 import type { RegistrySkill } from '@/data/registry';
-import { PLATFORMS } from '@/store/useStore';
+import { useStore } from '@/store/useStore';
+import { errorMessage, isCommandError } from '@/lib/utils';
 
 interface InstallAllResult {
   installed: string[];
   skipped: string[];
 }
 
+/** 安全扫描达到阈值时后端返回的 security_review_required 详情 */
+interface SecurityReview {
+  highest: string;
+  findings: number;
+  summary: string;
+}
+
+/** 用户确认风险后重新安装时使用的扫描策略 */
+const ACCEPT_RISK_POLICY = { accept_risk: true };
+
 interface InstallTargetModalProps {
   skill: RegistrySkill;
   open: boolean;
@@ -26,6 +37,8 @@ export function InstallTargetModal({ skill, open: modalOpen, onOpenChange, onCom
   const [targetType, setTargetType] = useState<'all' | 'project'>('all');
   const [projectRoot, setProjectRoot] = useState('');
   const [loading, setLoading] = useState(false);
+  const [review, setReview] = useState<SecurityReview | null>(null);
+  const platforms = useStore((s) => s.platforms);
 
   const handleBrowseProject = useCallback(async () => {
     if (!('__TAURI__' in window)) {
@@ -42,14 +55,24 @@ export function InstallTargetModal({ skill, open: modalOpen, onOpenChange, onCom
         setProjectRoot(selected);
       }
     } catch (err) {
-      const msg = err instanceof Error ? err.message : String(err);
+      const msg = errorMessage(err);
       console.error('[InstallTargetModal] 选择目录失败:', err);
       toast.error(`选择目录失败: ${msg}`);
     }
   }, []);
 
+  /** 安全扫描需确认时展示报告，由用户决定是否仍然安装；其余错误直接提示 */
+  const handleInstallError = (err: unknown) => {
+    if (isCommandError(err) && err.code === 'security_review_required' && err.details) {
+      setReview(err.details as unknown as SecurityReview);
+      return;
+    }
+    const msg = errorMessage(err);
+    toast.error(msg || '安装失败');
+  };
+
   /** 一键安装到所有已检测平台 */
-  const handleInstallAll = async () => {
+  const handleInstallAll = async (acceptRisk = false) => {
     setLoading(true);
     try {
       const result = await invoke<InstallAllResult>('install_skill_to_all_platforms', {
@@ -57,11 +80,12 @@ export function InstallTargetModal({ skill, open: modalOpen, onOpenChange, onCom
           id: skill.id,
           repo: skill.repo,
           sub_path: skill.subPath ?? null,
+          scan_policy: acceptRisk ? ACCEPT_RISK_POLICY : null,
         },
       });
       const { installed, skipped } = result;
       if (installed.length > 0) {
-        const label = installed.map((p) => PLATFORMS.find((x) => x.value === p)?.label ?? p).join('、');
+        const label = installed.map((p) => platforms.find((x) => x.value === p)?.label ?? p).join('、');
         toast.success(`${skill.name} 已安装到 ${installed.length} 个平台（${label}）`);
       }
       if (skipped.length > 0 && installed.length === 0) {
@@ -72,46 +96,46 @@ export function InstallTargetModal({ skill, open: modalOpen, onOpenChange, onCom
         onComplete?.();
       }
     } catch (err) {
-      const msg = err instanceof Error ? err.message : String(err);
-      toast.error(msg || '安装失败');
+      handleInstallError(err);
     } finally {
       setLoading(false);
     }
   };
 
   /** 安装到项目 */
-  const handleInstallToProject = async () => {
+  const handleInstallToProject = async (acceptRisk = false) => {
     if (!projectRoot.trim()) {
       toast.error('请选择项目根目录');
       return;
     }
     setLoading(true);
     try {
-      await invoke<string>('install_skill', {
+      await invoke('install_skill', {
         payload: {
           id: skill.id,
           repo: skill.repo,
           sub_path: skill.subPath ?? null,
           target_platform: 'claude',
           project_root: projectRoot,
+          scan_policy: acceptRisk ? ACCEPT_RISK_POLICY : null,
         },
       });
       toast.success(`${skill.name} 已安装到项目`);
       onOpenChange(false);
       onComplete?.();
     } catch (err) {
-      const msg = err instanceof Error ? err.message : String(err);
-      toast.error(msg || '安装失败');
+      handleInstallError(err);
     } finally {
       setLoading(false);
     }
   };
 
-  const handleSubmit = () => {
+  const handleSubmit = (acceptRisk = false) => {
+    setReview(null);
     if (targetType === 'all') {
-      handleInstallAll();
+      handleInstallAll(acceptRisk);
     } else {
-      handleInstallToProject();
+      handleInstallToProject(acceptRisk);
     }
   };
 
@@ -125,60 +149,87 @@ export function InstallTargetModal({ skill, open: modalOpen, onOpenChange, onCom
           <DialogDescription>选择 {skill.name} 的安装方式</DialogDescription>
         </DialogHeader>
 
-        <div className="space-y-4 py-4">
-          {/* 一键安装到所有平台 */}
-          <button
-            type="button"
-            onClick={() => setTargetType('all')}
-            className={`w-full flex flex-col gap-1 p-4 rounded-lg border text-left transition-all ${
-              targetType === 'all' ? 'border-primary bg-primary/5' : 'border-border/60 hover:border-primary/30'
-            }`}
-          >
-            <span className="text-sm font-medium">一键安装到所有平台</span>
-            <span className="text-xs text-muted-foreground">
-              检测本机已安装的 agent（Claude Code、Antigravity、Gemini CLI），安装到未覆盖的平台，已安装的跳过
-            </span>
-          </button>
-
-          {/* 安装到项目 */}
-          <button
-            type="button"
-            onClick={() => setTargetType('project')}
-            className={`w-full flex flex-col gap-1 p-4 rounded-lg border text-left transition-all ${
-              targetType === 'project' ? 'border-primary bg-primary/5' : 'border-border/60 hover:border-primary/30'
-            }`}
-          >
-            <span className="text-sm font-medium">安装到项目</span>
-            <span className="text-xs text-muted-foreground">
-              选择项目根目录，由 npx skills add 自动检测并安装到对应子目录
-            </span>
-          </button>
-
-          {targetType === 'project' && (
-            <div className="space-y-2 pl-1">
-              <label className="text-sm font-medium">项目根目录</label>
-              <div className="flex gap-2">
-                <Input
-                  value={projectRoot}
-                  onChange={(e) => setProjectRoot(e.target.value)}
-                  placeholder="选择或输入项目路径"
-                  className="flex-1"
-                />
-                <Button type="button" variant="outline" size="icon" onClick={handleBrowseProject}>
-                  <FolderOpen className="h-4 w-4" />
-                </Button>
-              </div>
+        {review ? (
+          <div className="space-y-3 py-4">
+            <div className="flex items-start gap-2 rounded-lg border border-destructive/40 bg-destructive/5 p-3 text-sm">
+              <ShieldAlert className="h-4 w-4 mt-0.5 shrink-0 text-destructive" />
+              <span>
+                安全扫描发现 {review.findings} 处风险（最高 {review.highest}），请确认内容可信后再安装
+              </span>
             </div>
-          )}
-        </div>
+            <pre className="max-h-60 overflow-auto rounded-lg bg-muted p-3 text-xs whitespace-pre-wrap break-all">
+              {review.summary}
+            </pre>
+          </div>
+        ) : (
+          <div className="space-y-4 py-4">
+            {/* 一键安装到所有平台 */}
+            <button
+              type="button"
+              onClick={() => setTargetType('all')}
+              className={`w-full flex flex-col gap-1 p-4 rounded-lg border text-left transition-all ${
+                targetType === 'all' ? 'border-primary bg-primary/5' : 'border-border/60 hover:border-primary/30'
+              }`}
+            >
+              <span className="text-sm font-medium">一键安装到所有平台</span>
+              <span className="text-xs text-muted-foreground">
+                检测本机已安装的 agent（Claude Code、Antigravity、Gemini CLI），安装到未覆盖的平台，已安装的跳过
+              </span>
+            </button>
+
+            {/* 安装到项目 */}
+            <button
+              type="button"
+              onClick={() => setTargetType('project')}
+              className={`w-full flex flex-col gap-1 p-4 rounded-lg border text-left transition-all ${
+                targetType === 'project' ? 'border-primary bg-primary/5' : 'border-border/60 hover:border-primary/30'
+              }`}
+            >
+              <span className="text-sm font-medium">安装到项目</span>
+              <span className="text-xs text-muted-foreground">
+                选择项目根目录，由 npx skills add 自动检测并安装到对应子目录
+              </span>
+            </button>
+
+            {targetType === 'project' && (
+              <div className="space-y-2 pl-1">
+                <label className="text-sm font-medium">项目根目录</label>
+                <div className="flex gap-2">
+                  <Input
+                    value={projectRoot}
+                    onChange={(e) => setProjectRoot(e.target.value)}
+                    placeholder="选择或输入项目路径"
+                    className="flex-1"
+                  />
+                  <Button type="button" variant="outline" size="icon" onClick={handleBrowseProject}>
+                    <FolderOpen className="h-4 w-4" />
+                  </Button>
+                </div>
+              </div>
+            )}
+          </div>
+        )}
 
         <DialogFooter className="gap-2">
-          <Button type="button" variant="outline" onClick={() => onOpenChange(false)} disabled={loading}>
-            取消
-          </Button>
-          <Button type="button" onClick={handleSubmit} disabled={loading || !canSubmit}>
-            {loading ? '安装中...' : targetType === 'all' ? '一键安装' : '安装到项目'}
-          </Button>
+          {review ? (
+            <>
+              <Button type="button" variant="outline" onClick={() => setReview(null)} disabled={loading}>
+                返回
+              </Button>
+              <Button type="button" variant="destructive" onClick={() => handleSubmit(true)} disabled={loading}>
+                {loading ? '安装中...' : '我已了解风险，仍然安装'}
+              </Button>
+            </>
+          ) : (
+            <>
+              <Button type="button" variant="outline" onClick={() => onOpenChange(false)} disabled={loading}>
+                取消
+              </Button>
+              <Button type="button" onClick={() => handleSubmit()} disabled={loading || !canSubmit}>
+                {loading ? '安装中...' : targetType === 'all' ? '一键安装' : '安装到项目'}
+              </Button>
+            </>
+          )}
         </DialogFooter>
       </DialogContent>
     </Dialog>
@@ -1,4 +1,5 @@
 import { useState, useEffect } from 'react';
+import { invoke } from '@tauri-apps/api/core';
 import {
   Dialog,
   DialogContent,
@@ -11,6 +12,7 @@ import { Button } from '@/components/ui/button';
 import { InstallButton } from './InstallButton';
 import type { RegistrySkill } from '@/data/registry';
 import { fetchSkillDescription, getCachedDescription } from '@/lib/skillDetail';
+import { useStore, type SkillFileContent } from '@/store/useStore';
 import { Loader2, ExternalLink, Download } from 'lucide-react';
 
 interface SkillDetailModalProps {
@@ -28,7 +30,7 @@ interface SkillDetailModalProps {
 
 /**
  * 技能详情弹窗
- * 展示从 skills.sh 详情页解析的描述信息
+ * 展示从 skills.sh 详情页解析的描述信息；本地已安装时额外预览完整 SKILL.md
  */
 export function SkillDetailModal({
   skill,
@@ -40,6 +42,21 @@ export function SkillDetailModal({
   const [description, setDescription] = useState<string>('');
   const [loading, setLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [localSkillMd, setLocalSkillMd] = useState<SkillFileContent | null>(null);
+  const localInstallPath = useStore(
+    (s) => s.installedSkills.find((m) => m.id === skill.id && m.enabled)?.install_path
+  );
+
+  // 打开时读取本地 SKILL.md
+  useEffect(() => {
+    if (!open || !localInstallPath) {
+      setLocalSkillMd(null);
+      return;
+    }
+    invoke<SkillFileContent>('read_skill_file', { installPath: localInstallPath, path: 'SKILL.md' })
+      .then(setLocalSkillMd)
+      .catch(() => setLocalSkillMd(null));
+  }, [open, localInstallPath]);
 
   // 打开时加载描述
   useEffect(() => {
@@ -118,6 +135,17 @@ export function SkillDetailModal({
                 </p>
               </div>
 
+              {localSkillMd?.content && (
+                <div className="space-y-1">
+                  <p className="text-xs text-muted-foreground">
+                    本地 SKILL.md{localSkillMd.truncated ? '（内容过长，已截断）' : ''}
+                  </p>
+                  <pre className="max-h-80 overflow-auto whitespace-pre-wrap rounded-md border border-border/40 bg-secondary/30 p-4 text-xs leading-relaxed">
+                    {localSkillMd.content}
+                  </pre>
+                </div>
+              )}
+
               {/* 仓库信息 */}
               <div className="text-sm text-muted-foreground">
                 <p>
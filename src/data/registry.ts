@@ -10,7 +10,7 @@ export interface RegistrySkill {
   description: string;
   category: string;
   tags: string[];
-  platforms: ('claude' | 'cursor')[];
+  platforms: string[];
   stars: number;
   install_mode: 'sparse' | 'full';
   author: string;
@@ -1,10 +1,8 @@
 import { useState, useEffect, useCallback } from 'react';
-import { fetch as tauriFetch } from '@tauri-apps/plugin-http';
 import type { RegistrySkill } from '@/data/registry';
+import { fetchText } from '@/lib/http';
 import { getSnapshot, setSnapshot } from '@/lib/skillsCache';
-
-/** Tauri 环境用插件 fetch 绕过 CORS，浏览器用原生 fetch */
-const getFetch = () => ('__TAURI__' in window ? tauriFetch : fetch);
+import { errorMessage } from '@/lib/utils';
 
 /**
  * 排序类型
@@ -193,13 +191,7 @@ export function useSkillsFromAPI(
         const path = sort ? `/${sort}` : '/';
         const baseUrl = import.meta.env.DEV ? '/api/skills-sh' : 'https://skills.sh';
         const url = `${baseUrl}${path}`;
-        const response = await getFetch()(url);
-
-        if (!response.ok) {
-          throw new Error(`请求失败: ${response.status}`);
-        }
-
-        const html = await response.text();
+        const html = await fetchText(url);
         let parsedSkills: RegistrySkill[];
 
         if (typeof DOMParser !== 'undefined') {
@@ -217,9 +209,7 @@ export function useSkillsFromAPI(
         setSnapshot(sort, limitedSkills, parsedSkills.length > limit);
       } catch (err) {
         if (!silent) {
-          const message =
-            err instanceof Error ? err.message : '获取技能列表失败';
-          setError(message);
+          setError(errorMessage(err) || '获取技能列表失败');
           setSkills([]);
         }
       } finally {
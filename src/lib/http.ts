@@ -0,0 +1,18 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * GET 文本内容
+ * Tauri 环境交给后端 http_get_text（应用设置中的 URL 改写、代理与 CA），浏览器 / 开发代理路径用原生 fetch
+ * @param url - 请求地址
+ * @returns 响应文本
+ */
+export async function fetchText(url: string): Promise<string> {
+  if ('__TAURI__' in window && url.startsWith('https://')) {
+    return invoke<string>('http_get_text', { url });
+  }
+  const response = await fetch(url);
+  if (!response.ok) {
+    throw new Error(`请求失败: ${response.status}`);
+  }
+  return response.text();
+}
@@ -3,10 +3,7 @@
  * 从 skills.sh 详情页获取 SKILL.md 描述并缓存
  */
 
-import { fetch as tauriFetch } from '@tauri-apps/plugin-http';
-
-/** Tauri 环境用插件 fetch 绕过 CORS，浏览器用原生 fetch */
-const getFetch = () => ('__TAURI__' in window ? tauriFetch : fetch);
+import { fetchText } from '@/lib/http';
 
 /** 描述缓存：key 为 `${source}/${skillId}` */
 const descriptionCache = new Map<string, string>();
@@ -119,13 +116,7 @@ export async function fetchSkillDescription(
   try {
     const baseUrl = import.meta.env.DEV ? '/api/skills-sh' : 'https://skills.sh';
     const url = `${baseUrl}/${source}/${skillId}`;
-    const response = await getFetch()(url);
-
-    if (!response.ok) {
-      throw new Error(`请求失败: ${response.status}`);
-    }
-
-    const html = await response.text();
+    const html = await fetchText(url);
     const description = parseDescriptionFromHtml(html);
 
     // 写入缓存
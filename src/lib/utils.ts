@@ -4,3 +4,21 @@ import { twMerge } from "tailwind-merge"
 export function cn(...inputs: ClassValue[]) {
   return twMerge(clsx(inputs))
 }
+
+/** 后端命令错误（见 src-tauri/src/commands/error.rs） */
+export interface CommandError {
+  code: string
+  message: string
+  details?: Record<string, unknown>
+}
+
+export function isCommandError(err: unknown): err is CommandError {
+  return typeof err === "object" && err !== null && "code" in err && "message" in err
+}
+
+/** 提取可展示的错误信息（兼容后端 CommandError、Error 与字符串） */
+export function errorMessage(err: unknown): string {
+  if (isCommandError(err)) return err.message
+  if (err instanceof Error) return err.message
+  return String(err)
+}
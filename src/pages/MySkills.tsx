@@ -1,12 +1,13 @@
 import { useEffect, useMemo, useState } from 'react';
 import { invoke } from '@tauri-apps/api/core';
-import { Package, Trash2, Loader2 } from 'lucide-react';
-import { useStore, PLATFORMS, Platform } from '@/store/useStore';
+import { open, save } from '@tauri-apps/plugin-dialog';
+import { Package, Trash2, Loader2, FolderOpen, X, Power, Archive } from 'lucide-react';
+import { useStore } from '@/store/useStore';
 import { registrySkills } from '@/data/registry';
 import { Badge } from '@/components/ui/badge';
 import { Button } from '@/components/ui/button';
 import { toast } from 'sonner';
-import { cn } from '@/lib/utils';
+import { cn, errorMessage } from '@/lib/utils';
 
 function PlatformTab({ label, active, onClick }: { label: string; active: boolean; onClick: () => void }) {
   return (
@@ -30,29 +31,85 @@ function PlatformTab({ label, active, onClick }: { label: string; active: boolea
  */
 export function MySkills() {
   const [unloadingId, setUnloadingId] = useState<string | null>(null);
-  const { installedSkills, platform, setPlatform, refreshInstalledSkills } = useStore();
+  const { platforms, installedSkills, platform, setPlatform, projectRoot, setProjectRoot, refreshInstalledSkills } =
+    useStore();
 
   useEffect(() => {
     refreshInstalledSkills().catch(() => {});
-  }, [platform, refreshInstalledSkills]);
+  }, [platform, projectRoot, refreshInstalledSkills]);
+
+  const handleBrowseProject = async () => {
+    if (!('__TAURI__' in window)) {
+      toast.info('请在 Tauri 桌面应用中浏览目录');
+      return;
+    }
+    try {
+      const selected = await open({ directory: true, multiple: false, title: '选择项目根目录' });
+      if (selected && typeof selected === 'string') {
+        setProjectRoot(selected);
+      }
+    } catch (err) {
+      toast.error(`选择目录失败: ${errorMessage(err)}`);
+    }
+  };
 
   const items = useMemo(() => {
     const registryMap = new Map(registrySkills.map((s) => [s.id, s]));
     return installedSkills.map((s) => ({ meta: s, skill: registryMap.get(s.id) }));
   }, [installedSkills]);
 
+  const handleToggle = async (meta: typeof installedSkills[0]) => {
+    setUnloadingId(meta.install_path);
+    try {
+      await invoke('toggle_skill', {
+        platform,
+        skillId: meta.id,
+        enabled: !meta.enabled,
+        projectRoot: meta.scope === 'project' ? projectRoot : null,
+      });
+      toast.success(meta.enabled ? '已停用' : '已启用');
+      refreshInstalledSkills().catch(() => {});
+    } catch (err) {
+      toast.error(errorMessage(err) || '操作失败');
+    } finally {
+      setUnloadingId(null);
+    }
+  };
+
+  const handleExport = async (meta: typeof installedSkills[0]) => {
+    if (!('__TAURI__' in window)) {
+      toast.info('请在 Tauri 桌面应用中导出');
+      return;
+    }
+    try {
+      const dest = await save({
+        title: '导出 Skill',
+        defaultPath: `${meta.id}.zip`,
+        filters: [
+          { name: 'Zip', extensions: ['zip'] },
+          { name: 'tar.gz', extensions: ['tar.gz', 'tgz'] },
+        ],
+      });
+      if (!dest) return;
+      await invoke('export_skill', { installPath: meta.install_path, dest, format: null });
+      toast.success(`已导出到 ${dest}`);
+    } catch (err) {
+      toast.error(errorMessage(err) || '导出失败');
+    }
+  };
+
   const handleUninstall = async (meta: typeof installedSkills[0]) => {
     if (!confirm(`确定要卸载 "${meta.name ?? meta.id}" 吗？此操作不可恢复。`)) {
       return;
     }
 
-    setUnloadingId(meta.id);
+    setUnloadingId(meta.install_path);
     try {
       await invoke<void>('uninstall_skill', { skillId: meta.id, installPath: meta.install_path });
       toast.success('卸载成功');
       refreshInstalledSkills().catch(() => {});
     } catch (err) {
-      const msg = err instanceof Error ? err.message : String(err);
+      const msg = errorMessage(err);
       toast.error(msg || '卸载失败');
     } finally {
       setUnloadingId(null);
@@ -68,14 +125,30 @@ export function MySkills() {
         </div>
       </div>
 
+      {/* 项目选择：设置后同时列出项目级 skills */}
+      <div className="flex items-center gap-2 text-sm">
+        <Button type="button" variant="outline" size="sm" onClick={handleBrowseProject}>
+          <FolderOpen className="mr-1 h-4 w-4" />
+          {projectRoot ? '切换项目' : '选择项目'}
+        </Button>
+        {projectRoot && (
+          <>
+            <code className="truncate text-muted-foreground">{projectRoot}</code>
+            <Button type="button" variant="ghost" size="sm" onClick={() => setProjectRoot(null)}>
+              <X className="h-4 w-4" />
+            </Button>
+          </>
+        )}
+      </div>
+
       {/* 平台切换 */}
-      <div className="flex gap-2">
-        {PLATFORMS.map((p) => (
+      <div className="flex flex-wrap gap-2">
+        {platforms.map((p) => (
           <PlatformTab
             key={p.value}
             label={p.label}
             active={platform === p.value}
-            onClick={() => setPlatform(p.value as Platform)}
+            onClick={() => setPlatform(p.value)}
           />
         ))}
       </div>
@@ -90,7 +163,7 @@ export function MySkills() {
         <div className="space-y-3">
           {items.map(({ meta, skill }) => (
             <div
-              key={meta.id}
+              key={meta.install_path}
               className="rounded-lg border border-border/60 bg-card/50 p-4 backdrop-blur-sm transition-all duration-200 hover:border-primary/20"
             >
               <div className="flex items-start justify-between gap-3">
@@ -122,16 +195,38 @@ export function MySkills() {
                   </p>
                 </div>
                 <div className="flex items-center gap-2">
-                  <Badge variant="outline">已安装</Badge>
+                  <Badge variant="outline">{meta.scope === 'project' ? '项目' : '全局'}</Badge>
+                  {!meta.enabled && <Badge variant="secondary">已停用</Badge>}
+                  <Button
+                    type="button"
+                    variant="ghost"
+                    size="sm"
+                    title={meta.enabled ? '停用' : '启用'}
+                    className={cn(!meta.enabled && 'text-muted-foreground')}
+                    onClick={() => handleToggle(meta)}
+                    disabled={unloadingId === meta.install_path}
+                  >
+                    <Power className="h-4 w-4" />
+                  </Button>
+                  <Button
+                    type="button"
+                    variant="ghost"
+                    size="sm"
+                    title="导出"
+                    onClick={() => handleExport(meta)}
+                    disabled={unloadingId === meta.install_path}
+                  >
+                    <Archive className="h-4 w-4" />
+                  </Button>
                   <Button
                     type="button"
                     variant="ghost"
                     size="sm"
                     className="text-destructive hover:text-destructive hover:bg-destructive/10"
                     onClick={() => handleUninstall(meta)}
-                    disabled={unloadingId === meta.id}
+                    disabled={unloadingId === meta.install_path}
                   >
-                    {unloadingId === meta.id ? (
+                    {unloadingId === meta.install_path ? (
                       <Loader2 className="h-4 w-4 animate-spin" />
                     ) : (
                       <Trash2 className="h-4 w-4" />
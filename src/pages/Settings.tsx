@@ -1,9 +1,66 @@
-import { PLATFORMS } from '@/store/useStore';
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { useStore } from '@/store/useStore';
+
+interface ResolvedDir {
+  path: string;
+  source: 'env' | 'settings' | 'default';
+  env_var?: string | null;
+}
+
+interface PlatformPaths {
+  id: string;
+  label: string;
+  config_dir: ResolvedDir;
+  global_dir: ResolvedDir;
+}
+
+type DetectionEvidence = 'path' | 'known_location' | 'config_dir' | 'skills_dir';
+
+interface PlatformDetection {
+  id: string;
+  installed: boolean;
+  evidence: DetectionEvidence[];
+  version?: string | null;
+}
+
+const EVIDENCE_LABELS: Record<DetectionEvidence, string> = {
+  path: 'PATH',
+  known_location: '常见安装位置',
+  config_dir: '配置目录',
+  skills_dir: 'skills 目录',
+};
+
+function detectionLabel(d: PlatformDetection): string {
+  const basis = d.evidence.map((e) => EVIDENCE_LABELS[e]).join('、');
+  if (!d.installed) return basis ? `未检测到可执行文件（存在${basis}）` : '未检测到';
+  return `${d.version ?? '已安装'}（${basis}）`;
+}
+
+function sourceLabel(dir: ResolvedDir): string {
+  if (dir.source === 'env') return `环境变量 ${dir.env_var ?? ''}`.trim();
+  if (dir.source === 'settings') return '设置覆盖';
+  return '默认';
+}
 
 /**
  * 设置页 - 应用配置
  */
 export function Settings() {
+  const platforms = useStore((s) => s.platforms);
+  const [paths, setPaths] = useState<PlatformPaths[] | null>(null);
+  const [detections, setDetections] = useState<Record<string, PlatformDetection>>({});
+
+  useEffect(() => {
+    if (!('__TAURI__' in window)) return;
+    invoke<PlatformPaths[]>('get_platform_paths')
+      .then(setPaths)
+      .catch(() => {});
+    invoke<PlatformDetection[]>('detect_platforms')
+      .then((list) => setDetections(Object.fromEntries(list.map((d) => [d.id, d]))))
+      .catch(() => {});
+  }, []);
+
   return (
     <div className="flex flex-1 flex-col gap-6 p-6">
       <div>
@@ -15,12 +72,25 @@ export function Settings() {
         <div className="rounded-lg border border-border/60 bg-card/50 p-4 backdrop-blur-sm">
           <h3 className="font-medium">平台路径</h3>
           <div className="mt-3 space-y-2">
-            {PLATFORMS.map((p) => (
-              <div key={p.value} className="flex items-center gap-2 text-sm">
-                <span className="font-medium text-foreground min-w-[100px]">{p.label}:</span>
-                <code className="text-muted-foreground">{p.globalPath}</code>
-              </div>
-            ))}
+            {paths
+              ? paths.map((p) => (
+                  <div key={p.id} className="flex items-center gap-2 text-sm">
+                    <span className="font-medium text-foreground min-w-[100px]">{p.label}:</span>
+                    <code className="text-muted-foreground">{p.global_dir.path}</code>
+                    <span className="text-xs text-muted-foreground/60">（{sourceLabel(p.global_dir)}）</span>
+                    {detections[p.id] && (
+                      <span className="ml-auto text-xs text-muted-foreground">
+                        {detectionLabel(detections[p.id])}
+                      </span>
+                    )}
+                  </div>
+                ))
+              : platforms.map((p) => (
+                  <div key={p.value} className="flex items-center gap-2 text-sm">
+                    <span className="font-medium text-foreground min-w-[100px]">{p.label}:</span>
+                    <code className="text-muted-foreground">{p.globalPath}</code>
+                  </div>
+                ))}
           </div>
         </div>
 
@@ -30,7 +100,7 @@ export function Settings() {
             SkillHub v0.1.0 - AI 助手 Skills 管理工具
           </p>
           <p className="mt-2 text-xs text-muted-foreground/60">
-            支持 Claude Code、Antigravity、Gemini CLI 的全局与项目级安装
+            支持主流 AI 编程助手的全局与项目级安装，可在 ~/.skillhub/platforms.json 中添加自定义平台
           </p>
         </div>
       </div>
@@ -1,14 +1,47 @@
 import { create } from 'zustand';
 import { invoke } from '@tauri-apps/api/core';
 
-export type Platform = 'claude' | 'antigravity' | 'gemini';
+/** 平台 id：内置平台或 ~/.skillhub/platforms.json 中的自定义平台 */
+export type Platform = string;
 
-export const PLATFORMS: { value: Platform; label: string; globalPath: string }[] = [
+export interface PlatformOption {
+  value: Platform;
+  label: string;
+  globalPath: string;
+}
+
+/** 后端平台定义（get_platforms） */
+export interface PlatformSpec {
+  id: string;
+  label: string;
+  detect_path: string;
+  global_dir: string;
+  project_dir: string;
+  agent?: string | null;
+  features: ('global_install' | 'project_install' | 'npx_install')[];
+  builtin: boolean;
+}
+
+/** 内置平台（后端平台列表加载前的默认值） */
+export const PLATFORMS: PlatformOption[] = [
   { value: 'claude', label: 'Claude Code', globalPath: '~/.claude/skills/' },
   { value: 'antigravity', label: 'Antigravity', globalPath: '~/.gemini/antigravity/skills/' },
   { value: 'gemini', label: 'Gemini CLI', globalPath: '~/.gemini/skills/' },
+  { value: 'cursor', label: 'Cursor', globalPath: '~/.cursor/skills/' },
+  { value: 'codex', label: 'Codex CLI', globalPath: '~/.codex/skills/' },
+  { value: 'opencode', label: 'OpenCode', globalPath: '~/.config/opencode/skill/' },
+  { value: 'windsurf', label: 'Windsurf', globalPath: '~/.codeium/windsurf/skills/' },
+  { value: 'copilot', label: 'GitHub Copilot', globalPath: '~/.copilot/skills/' },
 ];
 
+export interface LicenseInfo {
+  spdx?: string | null;
+  source?: string | null;
+  flagged: boolean;
+  flag_reason?: string | null;
+  unresolved_reason?: string | null;
+}
+
 export interface InstalledSkillMeta {
   id: string;
   name?: string | null;
@@ -16,12 +49,57 @@ export interface InstalledSkillMeta {
   tags: string[];
   install_path: string;
   skill_md_path?: string | null;
+  license: LicenseInfo;
+  scope: SkillScope;
+  /** 停用的 skill 位于 SkillHub 停用区，agent 不可见 */
+  enabled: boolean;
+  /** SKILL.md frontmatter 完整内容；缺失或解析失败时为 null */
+  frontmatter?: Record<string, unknown> | null;
+  frontmatter_diagnostics: FrontmatterDiagnostic[];
+}
+
+export interface FrontmatterDiagnostic {
+  severity: 'info' | 'warning' | 'error';
+  /** SKILL.md 中的行号（从 1 开始） */
+  line?: number | null;
+  message: string;
+}
+
+/** read_skill_file 返回的文件内容 */
+export interface SkillFileContent {
+  path: string;
+  size: number;
+  /** 二进制文件不返回内容 */
+  binary: boolean;
+  /** 超过读取上限，仅返回开头部分 */
+  truncated: boolean;
+  content?: string | null;
+}
+
+export type SkillScope = 'global' | 'project';
+
+export interface SkillLocation {
+  platform: Platform;
+  scope: SkillScope;
+}
+
+function toPlatformOption(spec: PlatformSpec): PlatformOption {
+  const dir = spec.global_dir.replace(/\/+$/, '');
+  const isAbsolute = dir.startsWith('/') || /^[A-Za-z]:/.test(dir);
+  return { value: spec.id, label: spec.label, globalPath: `${isAbsolute ? '' : '~/'}${dir}/` };
 }
 
 interface AppStore {
+  /** 所有平台（内置 + 用户自定义） */
+  platforms: PlatformOption[];
+  loadPlatforms: () => Promise<void>;
+
   /** 当前平台（决定扫描哪个 skills 目录） */
   platform: Platform;
   setPlatform: (platform: Platform) => void;
+  /** 当前项目根目录（设置后同时列出项目级 skills） */
+  projectRoot: string | null;
+  setProjectRoot: (projectRoot: string | null) => void;
 
   /** 本地已安装 skills（目录 + 解析后的元数据） */
   installedSkills: InstalledSkillMeta[];
@@ -36,8 +114,17 @@ interface AppStore {
  * 全局状态（MVP）
  */
 export const useStore = create<AppStore>((set, get) => ({
+  platforms: PLATFORMS,
+  loadPlatforms: async () => {
+    if (!('__TAURI__' in window)) return;
+    const specs = await invoke<PlatformSpec[]>('get_platforms');
+    set({ platforms: specs.map(toPlatformOption) });
+  },
+
   platform: 'claude',
   setPlatform: (platform) => set({ platform }),
+  projectRoot: null,
+  setProjectRoot: (projectRoot) => set({ projectRoot }),
 
   installedSkills: [],
   installedSkillIds: [],
@@ -47,17 +134,21 @@ export const useStore = create<AppStore>((set, get) => ({
       set({ installedSkills: [], installedSkillIds: [], installedPlatformsBySkillId: {} });
       return;
     }
-    const platform = get().platform;
+    const { platform, projectRoot } = get();
     const [skills, idsAnywhere] = await Promise.all([
-      invoke<InstalledSkillMeta[]>('get_installed_skills', { platform }),
-      invoke<string[]>('get_installed_skill_ids_anywhere'),
+      invoke<InstalledSkillMeta[]>('get_installed_skills', { platform, projectRoot }),
+      invoke<string[]>('get_installed_skill_ids_anywhere', { projectRoot }),
     ]);
     let platformsBySkillId: Record<string, string[]> = {};
     if (idsAnywhere.length > 0) {
       try {
-        platformsBySkillId = await invoke<Record<string, string[]>>('get_installed_platforms_for_skills', {
+        const locations = await invoke<Record<string, SkillLocation[]>>('get_installed_platforms_for_skills', {
           ids: idsAnywhere,
+          projectRoot,
         });
+        platformsBySkillId = Object.fromEntries(
+          Object.entries(locations).map(([id, locs]) => [id, [...new Set(locs.map((l) => l.platform))]])
+        );
       } catch {
         // 忽略批量接口失败
       }